@@ -3,9 +3,9 @@
 // Can be removed once https://github.com/rust-lang/rustfmt/issues/3362 is resolved.
 #[rustfmt::skip]
 use alloc::vec;
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 
-use casperlabs_types::{bytesrepr::ToBytes, CLTyped, CLValue, CLValueError};
+use casperlabs_types::{bytesrepr::ToBytes, named_arg::NamedArg, CLTyped, CLValue, CLValueError};
 
 /// Types which implement [`ArgsParser`] can be parsed into an ABI-compliant byte representation
 /// suitable for passing as arguments to a contract.
@@ -17,6 +17,15 @@ pub trait ArgsParser {
     fn parse(self) -> Result<Vec<CLValue>, CLValueError>;
 }
 
+/// Pairs `value` with `name` so that it can be passed as a named argument and looked up by the
+/// contract via [`runtime::get_named_arg`](crate::contract_api::runtime::get_named_arg).
+///
+/// The resulting [`NamedArg`] can be used as an element of any tuple implementing [`ArgsParser`],
+/// e.g. `(named_arg("amount", amount), 7u8)`.
+pub fn named_arg<T: CLTyped + ToBytes>(name: &str, value: T) -> NamedArg<T> {
+    NamedArg::new(String::from(name), value)
+}
+
 impl ArgsParser for () {
     fn parse(self) -> Result<Vec<CLValue>, CLValueError> {
         Ok(Vec::new())
@@ -7,11 +7,12 @@ use alloc::{collections::BTreeMap, string::String, vec::Vec};
 use core::mem::MaybeUninit;
 
 use casperlabs_types::{
-    account::PublicKey,
+    account::{PublicKey, Signature},
     api_error,
-    bytesrepr::{self, FromBytes},
-    ApiError, BlockTime, CLTyped, CLValue, ContractRef, Key, Phase, URef,
-    BLOCKTIME_SERIALIZED_LENGTH, PHASE_SERIALIZED_LENGTH,
+    bytesrepr::{self, BigEndian, FromBytes},
+    named_arg, ApiError, BlockTime, CLTyped, CLValue, CallStackElement, ContractRef, Key, Phase,
+    URef, BLAKE2B_DIGEST_LENGTH, BLOCKTIME_SERIALIZED_LENGTH, KEY_HASH_LENGTH,
+    PHASE_SERIALIZED_LENGTH, REVERT_MESSAGE_MAX_LENGTH, U512,
 };
 
 use crate::{args_parser::ArgsParser, contract_api, ext_ffi, unwrap_or_revert::UnwrapOrRevert};
@@ -38,6 +39,18 @@ pub fn revert<T: Into<ApiError>>(error: T) -> ! {
     }
 }
 
+/// Stops execution of a contract and reverts execution effects with a given [`ApiError`] and a
+/// human-readable message.
+///
+/// The message is returned to the caller via the deploy response alongside the error.  The host
+/// reads at most [`REVERT_MESSAGE_MAX_LENGTH`] bytes of it, truncating longer messages.
+pub fn revert_with_message<T: Into<ApiError>>(error: T, message: &str) -> ! {
+    let (message_ptr, message_size, _bytes) = contract_api::to_ptr(message);
+    unsafe {
+        ext_ffi::revert_with_message(error.into().into(), message_ptr, message_size);
+    }
+}
+
 /// Calls the given stored contract, passing the given arguments to it.
 ///
 /// If the stored contract calls [`ret`], then that value is returned from `call_contract`.  If the
@@ -116,6 +129,96 @@ fn get_arg_size(i: u32) -> Option<usize> {
 /// Note that this is only relevant to contracts stored on-chain since a contract deployed directly
 /// is not invoked with any arguments.
 pub fn get_arg<T: FromBytes>(i: u32) -> Option<Result<T, bytesrepr::Error>> {
+    let arg_bytes = get_arg_bytes(i)?;
+    Some(bytesrepr::deserialize(arg_bytes))
+}
+
+/// An error returned by [`get_arg_checked`] and [`read_arg_into`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ArgError {
+    /// The requested argument was not passed.  Converts to [`ApiError::MissingArgument`].
+    Missing,
+    /// The requested argument couldn't be deserialized.  Converts to
+    /// [`ApiError::InvalidArgument`].
+    Deserialization(bytesrepr::Error),
+    /// The buffer passed to [`read_arg_into`] is too small to hold the requested argument.
+    /// Converts to [`ApiError::BufferTooSmall`].
+    BufferTooSmall,
+}
+
+impl From<ArgError> for ApiError {
+    fn from(error: ArgError) -> Self {
+        match error {
+            ArgError::Missing => ApiError::MissingArgument,
+            ArgError::Deserialization(_) => ApiError::InvalidArgument,
+            ArgError::BufferTooSmall => ApiError::BufferTooSmall,
+        }
+    }
+}
+
+impl From<ArgError> for u32 {
+    fn from(error: ArgError) -> Self {
+        ApiError::from(error).into()
+    }
+}
+
+/// Returns the i-th argument, or an [`ArgError`] distinguishing a missing argument from one which
+/// couldn't be deserialized as a `T`.
+///
+/// As `ArgError` converts into an [`ApiError`], the result can be passed straight to
+/// [`unwrap_or_revert`](crate::unwrap_or_revert::UnwrapOrRevert::unwrap_or_revert).
+pub fn get_arg_checked<T: FromBytes>(i: u32) -> Result<T, ArgError> {
+    get_arg(i)
+        .ok_or(ArgError::Missing)?
+        .map_err(ArgError::Deserialization)
+}
+
+/// Returns the i-th argument after checking it with the given validation function.
+///
+/// Reverts with [`ApiError::MissingArgument`] if the argument is absent and with
+/// [`ApiError::InvalidArgument`] if it can't be deserialized as a `T`.  If the decoded value is
+/// rejected by `validate`, reverts with the code it returned, converted via [`ApiError::from`], so
+/// that a validation failure can be distinguished from a decoding failure.
+pub fn get_arg_validated<T: FromBytes>(i: u32, validate: impl Fn(&T) -> Result<(), u32>) -> T {
+    let value: T = get_arg(i)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    if let Err(code) = validate(&value) {
+        revert(ApiError::from(code));
+    }
+    value
+}
+
+/// Returns the i-th argument, decoding it from big-endian byte order.
+///
+/// Arguments are little-endian by default, as decoded by [`get_arg`].  This is intended for
+/// integer arguments which were passed wrapped in a [`BigEndian`].
+pub fn get_arg_be<T>(i: u32) -> Option<Result<T, bytesrepr::Error>>
+where
+    BigEndian<T>: FromBytes,
+{
+    get_arg::<BigEndian<T>>(i).map(|result| result.map(|BigEndian(value)| value))
+}
+
+/// Copies the raw bytes of the i-th argument into `buf`, returning the number of bytes written.
+///
+/// Unlike [`get_arg`], this doesn't allocate, which suits fixed-size arguments such as a
+/// [`PublicKey`].  Returns [`ArgError::Missing`] if the argument is absent, or
+/// [`ArgError::BufferTooSmall`] if `buf` can't hold it.
+pub fn read_arg_into(i: u32, buf: &mut [u8]) -> Result<usize, ArgError> {
+    let arg_size = get_arg_size(i).ok_or(ArgError::Missing)?;
+    if arg_size > buf.len() {
+        return Err(ArgError::BufferTooSmall);
+    }
+    if arg_size > 0 {
+        let ret = unsafe { ext_ffi::get_arg(i as usize, buf.as_mut_ptr(), arg_size) };
+        // Assumed to be safe as `get_arg_size` checks the argument already
+        api_error::result_from(ret).unwrap_or_revert();
+    }
+    Ok(arg_size)
+}
+
+fn get_arg_bytes(i: u32) -> Option<Vec<u8>> {
     let arg_size = get_arg_size(i)?;
     let arg_bytes = if arg_size > 0 {
         let res = {
@@ -131,7 +234,77 @@ pub fn get_arg<T: FromBytes>(i: u32) -> Option<Result<T, bytesrepr::Error>> {
         // Avoids allocation with 0 bytes and a call to get_arg
         Vec::new()
     };
-    Some(bytesrepr::deserialize(arg_bytes))
+    Some(arg_bytes)
+}
+
+/// Returns the total size in bytes of the serialized argument list passed to the current module
+/// invocation.
+///
+/// This allows a contract to bound its work, e.g. by reverting early when its inputs are
+/// unexpectedly large, before decoding any individual argument.
+pub fn total_args_size() -> usize {
+    let mut total_size: usize = 0;
+    let ret = unsafe { ext_ffi::get_total_args_size(&mut total_size as *mut usize) };
+    api_error::result_from(ret).unwrap_or_revert();
+    total_size
+}
+
+/// Returns the number of arguments passed to the current module invocation.
+///
+/// Returns `0` rather than reverting if no arguments were passed, so a contract accepting a
+/// variable number of arguments can iterate over `0..get_arg_count()`.
+pub fn get_arg_count() -> u32 {
+    let mut count: usize = 0;
+    let ret = unsafe { ext_ffi::get_arg_count(&mut count as *mut usize) };
+    api_error::result_from(ret).unwrap_or_revert();
+    count as u32
+}
+
+/// Returns the argument with the given name passed to the host for the current module invocation.
+///
+/// A named argument is passed as a [`NamedArg`](casperlabs_types::named_arg::NamedArg), e.g. via
+/// [`args_parser::named_arg`](crate::args_parser::named_arg), and can appear at any index.  The
+/// first named argument whose name equals `name` is deserialized as a `T`.  Positional arguments
+/// are skipped, even a `String` equal to `name`, so named and positional arguments can be mixed.
+pub fn get_named_arg<T: FromBytes>(name: &str) -> Option<Result<T, bytesrepr::Error>> {
+    for i in 0..get_arg_count() {
+        let arg_bytes = match get_arg_bytes(i) {
+            Some(arg_bytes) => arg_bytes,
+            None => continue,
+        };
+        let (arg_name, value_bytes) = match named_arg::split_name(&arg_bytes) {
+            Some(parsed) => parsed,
+            None => continue,
+        };
+        if arg_name == name {
+            return Some(bytesrepr::deserialize(value_bytes.to_vec()));
+        }
+    }
+    None
+}
+
+/// Returns the i-th argument as a version tag and the raw bytes of the payload which follows it.
+///
+/// A versioned argument is encoded as a `u8` version tag followed by the serialized payload, e.g.
+/// by passing a `(u8, T)` tuple as the argument.  This allows a contract to accept several layouts
+/// of the same argument by dispatching on the returned tag and deserializing the payload with
+/// [`bytesrepr::deserialize`] accordingly.
+///
+/// Returns `None` if the argument is missing, and `Some(Err(bytesrepr::Error::Formatting))` if the
+/// version tag is not one of `supported_versions`.
+pub fn get_arg_versioned(
+    i: u32,
+    supported_versions: &[u8],
+) -> Option<Result<(u8, Vec<u8>), bytesrepr::Error>> {
+    let arg_bytes = get_arg_bytes(i)?;
+    let result = match arg_bytes.split_first() {
+        Some((version, payload)) if supported_versions.contains(version) => {
+            Ok((*version, payload.to_vec()))
+        }
+        Some(_) => Err(bytesrepr::Error::Formatting),
+        None => Err(bytesrepr::Error::EarlyEndOfStream),
+    };
+    Some(result)
 }
 
 /// Returns the caller of the current context, i.e. the [`PublicKey`] of the account which made the
@@ -147,7 +320,49 @@ pub fn get_caller() -> PublicKey {
     bytesrepr::deserialize(buf).unwrap_or_revert()
 }
 
-/// Returns the current [`BlockTime`].
+/// Returns the call stack of the current deploy.
+///
+/// The first element is always the session or payment code of the deploy, and the last element
+/// is the currently executing code.  Each call to a stored contract adds one element.
+pub fn get_call_stack() -> Vec<CallStackElement> {
+    let output_size = {
+        let mut output_size = MaybeUninit::uninit();
+        let ret = unsafe { ext_ffi::get_call_stack(output_size.as_mut_ptr()) };
+        api_error::result_from(ret).unwrap_or_revert();
+        unsafe { output_size.assume_init() }
+    };
+    let buf = read_host_buffer(output_size).unwrap_or_revert();
+    bytesrepr::deserialize(buf).unwrap_or_revert()
+}
+
+/// Returns the immediate caller of the currently executing code.
+///
+/// Unlike [`get_caller`], which always returns the account which sent the deploy, this returns
+/// the element directly below the current one in the call stack, i.e. the stored contract which
+/// called the current one, or the session if it was called directly from session code.  If the
+/// stack has a depth of one, i.e. this is called from session or payment code itself, there is no
+/// immediate caller and `None` is returned.
+pub fn get_immediate_caller() -> Option<CallStackElement> {
+    get_call_stack().iter().rev().nth(1).cloned()
+}
+
+/// Returns the amount of gas the current deploy can still spend before running out of gas.
+///
+/// This allows a contract doing expensive work to stop and revert with its own error before the
+/// host aborts execution with an out-of-gas error.
+pub fn get_gas_remaining() -> U512 {
+    let output_size = {
+        let mut output_size = MaybeUninit::uninit();
+        let ret = unsafe { ext_ffi::get_gas_remaining(output_size.as_mut_ptr()) };
+        api_error::result_from(ret).unwrap_or_revert();
+        unsafe { output_size.assume_init() }
+    };
+    let buf = read_host_buffer(output_size).unwrap_or_revert();
+    bytesrepr::deserialize(buf).unwrap_or_revert()
+}
+
+/// Returns the current [`BlockTime`], i.e. the timestamp in milliseconds of the block containing
+/// the deploy being executed.
 pub fn get_blocktime() -> BlockTime {
     let dest_non_null_ptr = contract_api::alloc_bytes(BLOCKTIME_SERIALIZED_LENGTH);
     let bytes = unsafe {
@@ -175,6 +390,17 @@ pub fn get_phase() -> Phase {
     bytesrepr::deserialize(bytes).unwrap_or_revert()
 }
 
+/// Returns a pseudo-random seed for the current [`Phase`] of the deploy.
+///
+/// The seed is derived from the deploy hash, the block time and the phase, so it is reproducible
+/// when the deploy is replayed but differs between deploys.  It is not secret: anyone who knows
+/// the deploy can compute it, so it shouldn't be used where an unpredictable value is required.
+pub fn get_phase_seed() -> [u8; 32] {
+    let mut seed = [0u8; 32];
+    unsafe { ext_ffi::get_phase_seed(seed.as_mut_ptr()) };
+    seed
+}
+
 /// Returns the requested named [`Key`] from the current context.
 ///
 /// The current context is either the caller's account or a stored contract depending on whether the
@@ -202,6 +428,30 @@ pub fn get_key(name: &str) -> Option<Key> {
     Some(key)
 }
 
+/// Returns the requested named [`Key`] from the current context as a [`URef`].
+///
+/// Returns `None` if there is no key under `name`, and reverts with
+/// [`ApiError::UnexpectedKeyVariant`] if the key is not a `Key::URef`.
+pub fn get_key_as_uref(name: &str) -> Option<URef> {
+    let key = get_key(name)?;
+    let uref = key
+        .into_uref()
+        .unwrap_or_revert_with(ApiError::UnexpectedKeyVariant);
+    Some(uref)
+}
+
+/// Returns the requested named [`Key`] from the current context as the hash of a stored contract.
+///
+/// Returns `None` if there is no key under `name`, and reverts with
+/// [`ApiError::UnexpectedKeyVariant`] if the key is not a `Key::Hash`.
+pub fn get_key_as_hash(name: &str) -> Option<[u8; KEY_HASH_LENGTH]> {
+    let key = get_key(name)?;
+    let hash = key
+        .into_hash()
+        .unwrap_or_revert_with(ApiError::UnexpectedKeyVariant);
+    Some(hash)
+}
+
 /// Returns `true` if `name` exists in the current context's named keys.
 ///
 /// The current context is either the caller's account or a stored contract depending on whether the
@@ -222,7 +472,8 @@ pub fn put_key(name: &str, key: Key) {
     unsafe { ext_ffi::put_key(name_ptr, name_size, key_ptr, key_size) };
 }
 
-/// Removes the [`Key`] stored under `name` in the current context's named keys.
+/// Removes the [`Key`] stored under `name` in the current context's named keys.  Does nothing if
+/// there is no such key.
 ///
 /// The current context is either the caller's account or a stored contract depending on whether the
 /// currently-executing module is a direct call or a sub-call respectively.
@@ -235,6 +486,8 @@ pub fn remove_key(name: &str) {
 ///
 /// The current context is either the caller's account or a stored contract depending on whether the
 /// currently-executing module is a direct call or a sub-call respectively.
+///
+/// The returned map is ordered by name, so iterating over it is deterministic.
 pub fn list_named_keys() -> BTreeMap<String, Key> {
     let (total_keys, result_size) = {
         let mut total_keys = MaybeUninit::uninit();
@@ -253,6 +506,43 @@ pub fn list_named_keys() -> BTreeMap<String, Key> {
     bytesrepr::deserialize(bytes).unwrap_or_revert()
 }
 
+/// Returns `true` if `signature` is a valid signature of `message` made with the secret key
+/// corresponding to `public_key`.
+pub fn verify_signature(public_key: PublicKey, message: &[u8], signature: &Signature) -> bool {
+    let (public_key_ptr, public_key_size, _bytes1) = contract_api::to_ptr(public_key);
+    let (signature_ptr, signature_size, _bytes2) = contract_api::to_ptr(*signature);
+    let result = unsafe {
+        ext_ffi::verify_signature(
+            public_key_ptr,
+            public_key_size,
+            message.as_ptr(),
+            message.len(),
+            signature_ptr,
+            signature_size,
+        )
+    };
+    result != 0
+}
+
+/// Returns the BLAKE2b hash of `input`, computed by the host.
+///
+/// This is the same hash function used for global state hashes, and costs gas in proportion to the
+/// length of `input`.
+pub fn blake2b<T: AsRef<[u8]>>(input: T) -> [u8; BLAKE2B_DIGEST_LENGTH] {
+    let input = input.as_ref();
+    let mut digest = [0u8; BLAKE2B_DIGEST_LENGTH];
+    let ret = unsafe {
+        ext_ffi::blake2b(
+            input.as_ptr(),
+            input.len(),
+            digest.as_mut_ptr(),
+            digest.len(),
+        )
+    };
+    api_error::result_from(ret).unwrap_or_revert();
+    digest
+}
+
 /// Validates uref against named keys.
 pub fn is_valid_uref(uref: URef) -> bool {
     let (uref_ptr, uref_size, _bytes) = contract_api::to_ptr(uref);
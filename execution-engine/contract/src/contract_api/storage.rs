@@ -116,6 +116,10 @@ pub fn add_local<K: ToBytes, V: CLTyped + ToBytes>(key: K, value: V) {
 
 /// Stores the serialized bytes of an exported, non-mangled `extern "C"` function as a new contract
 /// under a [`URef`] generated by the host.
+///
+/// The returned [`ContractRef`] can be passed straight to
+/// [`runtime::call_contract`](crate::contract_api::runtime::call_contract), including from within
+/// the same deploy which stored the contract.
 pub fn store_function(name: &str, named_keys: BTreeMap<String, Key>) -> ContractRef {
     let (fn_ptr, fn_size, _bytes1) = contract_api::to_ptr(name);
     let (keys_ptr, keys_size, _bytes2) = contract_api::to_ptr(named_keys);
@@ -153,3 +157,66 @@ pub fn new_uref<T: CLTyped + ToBytes>(init: T) -> URef {
     };
     bytesrepr::deserialize(bytes).unwrap_or_revert()
 }
+
+/// Creates a new dictionary and stores it under `name` in the current context's named keys,
+/// replacing any existing key of that name.
+///
+/// A dictionary is identified by the returned [`URef`], and holds any number of values under
+/// string keys via [`dictionary_put`] and [`dictionary_get`].  Its entries are kept in the local
+/// partition of global state rather than in the named keys, so they don't grow the named-key map.
+/// Reading entries requires the `URef` to be readable, and writing requires it to be writeable.
+pub fn new_dictionary(name: &str) -> URef {
+    let dictionary = new_uref(());
+    runtime::put_key(name, dictionary.into());
+    dictionary
+}
+
+/// Reads the value under `key` in `dictionary`.
+///
+/// Returns `Ok(None)` if there is no value under `key`.
+pub fn dictionary_get<V: CLTyped + FromBytes>(
+    dictionary: URef,
+    key: &str,
+) -> Result<Option<V>, bytesrepr::Error> {
+    let (uref_ptr, uref_size, _bytes) = contract_api::to_ptr(dictionary);
+
+    let value_size = {
+        let mut value_size = MaybeUninit::uninit();
+        let ret = unsafe {
+            ext_ffi::dictionary_get(
+                uref_ptr,
+                uref_size,
+                key.as_ptr(),
+                key.len(),
+                value_size.as_mut_ptr(),
+            )
+        };
+        match api_error::result_from(ret) {
+            Ok(_) => unsafe { value_size.assume_init() },
+            Err(ApiError::ValueNotFound) => return Ok(None),
+            Err(e) => runtime::revert(e),
+        }
+    };
+
+    let value_bytes = runtime::read_host_buffer(value_size).unwrap_or_revert();
+    Ok(Some(bytesrepr::deserialize(value_bytes)?))
+}
+
+/// Writes `value` under `key` in `dictionary`, replacing any existing value.
+pub fn dictionary_put<V: CLTyped + ToBytes>(dictionary: URef, key: &str, value: V) {
+    let (uref_ptr, uref_size, _bytes1) = contract_api::to_ptr(dictionary);
+
+    let cl_value = CLValue::from_t(value).unwrap_or_revert();
+    let (cl_value_ptr, cl_value_size, _bytes2) = contract_api::to_ptr(cl_value);
+
+    unsafe {
+        ext_ffi::dictionary_put(
+            uref_ptr,
+            uref_size,
+            key.as_ptr(),
+            key.len(),
+            cl_value_ptr,
+            cl_value_size,
+        );
+    }
+}
@@ -0,0 +1,113 @@
+//! Home of the [`contract_error!`](crate::contract_error) macro, used to declare a contract's
+//! error codes.
+
+use core::u16;
+
+#[doc(hidden)]
+pub use casperlabs_types::ApiError;
+
+/// The largest discriminant allowed in an enum declared via
+/// [`contract_error!`](crate::contract_error), i.e. the largest code of an [`ApiError::User`].
+pub const USER_ERROR_CODE_MAX: u32 = u16::MAX as u32;
+
+/// Declares a `#[repr(u32)]` enum of user error codes for a contract.
+///
+/// The generated enum converts into [`ApiError::User`] and `u32`, so it can be passed directly to
+/// [`runtime::revert`](crate::contract_api::runtime::revert) and
+/// [`unwrap_or_revert_with`](crate::unwrap_or_revert::UnwrapOrRevert::unwrap_or_revert_with).  It
+/// also gets a `revert(self) -> !` convenience method.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use casperlabs_contract::{contract_api::runtime, contract_error};
+///
+/// contract_error! {
+///     /// Errors returned by this contract.
+///     pub enum Error {
+///         MissingAmount = 1,
+///         ZeroAmount,
+///     }
+/// }
+///
+/// # fn main() {
+/// assert_eq!(u32::from(Error::MissingAmount), 65_537);
+/// let amount: Option<u64> = runtime::get_arg(0).and_then(Result::ok);
+/// match amount {
+///     None => runtime::revert(Error::MissingAmount),
+///     Some(0) => Error::ZeroAmount.revert(),
+///     Some(_) => (),
+/// }
+/// # }
+/// ```
+///
+/// Discriminants must lie in the range reserved for user errors, i.e. not exceed
+/// [`USER_ERROR_CODE_MAX`], otherwise the declaration fails to compile:
+///
+/// ```rust,compile_fail
+/// use casperlabs_contract::contract_error;
+///
+/// contract_error! {
+///     pub enum Error {
+///         TooLarge = 65_536,
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
+/// This also applies to implicit discriminants:
+///
+/// ```rust,compile_fail
+/// use casperlabs_contract::contract_error;
+///
+/// contract_error! {
+///     pub enum Error {
+///         Largest = 65_535,
+///         TooLarge,
+///     }
+/// }
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! contract_error {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident $(= $value:expr)?),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        #[repr(u32)]
+        $vis enum $name {
+            $($(#[$variant_meta])* $variant $(= $value)?),+
+        }
+
+        // Fails to type check, naming the offending variant, if a discriminant is out of range.
+        $(
+            const _: [(); 0] =
+                [(); ($name::$variant as u32 > $crate::errors::USER_ERROR_CODE_MAX) as usize];
+        )+
+
+        impl $name {
+            /// Stops execution of the contract, reverting with this error.
+            #[allow(dead_code)]
+            $vis fn revert(self) -> ! {
+                $crate::contract_api::runtime::revert(self)
+            }
+        }
+
+        impl From<$name> for $crate::errors::ApiError {
+            fn from(error: $name) -> Self {
+                // Can't truncate, as discriminants are checked to fit in a `u16` above.
+                $crate::errors::ApiError::User(error as u32 as u16)
+            }
+        }
+
+        impl From<$name> for u32 {
+            fn from(error: $name) -> Self {
+                $crate::errors::ApiError::from(error).into()
+            }
+        }
+    };
+}
@@ -104,6 +104,36 @@ extern "C" {
     ) -> i32;
     pub fn get_main_purse(dest_ptr: *mut u8);
     pub fn read_host_buffer(dest_ptr: *mut u8, dest_size: usize, bytes_written: *mut usize) -> i32;
+    pub fn get_total_args_size(dest_size: *mut usize) -> i32;
+    pub fn verify_signature(
+        public_key_ptr: *const u8,
+        public_key_size: usize,
+        message_ptr: *const u8,
+        message_size: usize,
+        signature_ptr: *const u8,
+        signature_size: usize,
+    ) -> i32;
+    pub fn get_gas_remaining(output_size: *mut usize) -> i32;
+    pub fn get_arg_count(dest_count: *mut usize) -> i32;
+    pub fn revert_with_message(status: u32, message_ptr: *const u8, message_size: usize) -> !;
+    pub fn get_call_stack(output_size: *mut usize) -> i32;
+    pub fn blake2b(in_ptr: *const u8, in_size: usize, out_ptr: *mut u8, out_size: usize) -> i32;
+    pub fn dictionary_get(
+        uref_ptr: *const u8,
+        uref_size: usize,
+        key_ptr: *const u8,
+        key_size: usize,
+        output_size: *mut usize,
+    ) -> i32;
+    pub fn dictionary_put(
+        uref_ptr: *const u8,
+        uref_size: usize,
+        key_ptr: *const u8,
+        key_size: usize,
+        value_ptr: *const u8,
+        value_size: usize,
+    );
+    pub fn get_phase_seed(dest_ptr: *mut u8);
     #[cfg(feature = "test-support")]
     pub fn print(text_ptr: *const u8, text_size: usize);
 }
@@ -79,6 +79,7 @@ pub static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
 
 pub mod args_parser;
 pub mod contract_api;
+pub mod errors;
 pub mod ext_ffi;
 #[cfg(not(any(feature = "std", test)))]
 pub mod handlers;
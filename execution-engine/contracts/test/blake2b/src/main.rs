@@ -0,0 +1,25 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::ApiError;
+
+const DIGEST_NAME: &str = "digest";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let input: Vec<u8> = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    let digest = runtime::blake2b(&input);
+
+    runtime::put_key(DIGEST_NAME, storage::new_uref(digest).into());
+}
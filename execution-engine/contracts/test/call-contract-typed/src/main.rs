@@ -0,0 +1,45 @@
+#![no_std]
+#![no_main]
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, CLValue, U512};
+
+const ENTRY_FUNCTION_NAME: &str = "double";
+
+#[repr(u16)]
+enum Error {
+    ZeroAmount = 0,
+    UnexpectedResult,
+}
+
+fn get_amount_arg() -> U512 {
+    runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument)
+}
+
+/// Returns twice the given amount, reverting if it is zero.
+#[no_mangle]
+pub extern "C" fn double() {
+    let amount = get_amount_arg();
+    if amount.is_zero() {
+        runtime::revert(ApiError::User(Error::ZeroAmount as u16));
+    }
+    let result = CLValue::from_t(amount * U512::from(2)).unwrap_or_revert();
+    runtime::ret(result)
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let amount = get_amount_arg();
+
+    let contract_ref = storage::store_function(ENTRY_FUNCTION_NAME, Default::default());
+    let result: U512 = runtime::call_contract(contract_ref, (amount,));
+
+    if result != amount * U512::from(2) {
+        runtime::revert(ApiError::User(Error::UnexpectedResult as u16));
+    }
+}
@@ -0,0 +1,31 @@
+#![no_std]
+#![no_main]
+
+use contract::{contract_api::runtime, contract_error, unwrap_or_revert::UnwrapOrRevert};
+use types::ApiError;
+
+const REVERT_VIA_RUNTIME: u8 = 0;
+const REVERT_VIA_METHOD: u8 = 1;
+const REVERT_VIA_UNWRAP: u8 = 2;
+
+contract_error! {
+    enum Error {
+        First = 10,
+        Second,
+        Largest = 65_535,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let method: u8 = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    match method {
+        REVERT_VIA_RUNTIME => runtime::revert(Error::First),
+        REVERT_VIA_METHOD => Error::Second.revert(),
+        REVERT_VIA_UNWRAP => None::<()>.unwrap_or_revert_with(Error::Largest),
+        _ => runtime::revert(ApiError::InvalidArgument),
+    }
+}
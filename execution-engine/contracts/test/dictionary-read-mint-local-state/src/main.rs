@@ -0,0 +1,49 @@
+#![no_std]
+#![no_main]
+
+use core::mem::MaybeUninit;
+
+use contract::{
+    contract_api::{account, runtime, system},
+    ext_ffi,
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{api_error, bytesrepr::ToBytes, ApiError};
+
+#[repr(u16)]
+enum Error {
+    ReadMintLocalState = 0,
+}
+
+/// Tries to read the mint's local entry for the caller's main purse by passing the mint's URef to
+/// `dictionary_get`.
+///
+/// The mint keys its local state by the raw address of a purse, which isn't valid UTF-8 in general,
+/// so the key is passed to the host directly rather than via `storage::dictionary_get`.
+#[no_mangle]
+pub extern "C" fn call() {
+    let mint_uref = system::get_mint()
+        .into_uref()
+        .unwrap_or_revert_with(ApiError::UnexpectedContractRefVariant);
+    let uref_bytes = mint_uref.into_bytes().unwrap_or_revert();
+    let key_bytes = account::get_main_purse()
+        .addr()
+        .into_bytes()
+        .unwrap_or_revert();
+
+    let mut value_size = MaybeUninit::uninit();
+    let ret = unsafe {
+        ext_ffi::dictionary_get(
+            uref_bytes.as_ptr(),
+            uref_bytes.len(),
+            key_bytes.as_ptr(),
+            key_bytes.len(),
+            value_size.as_mut_ptr(),
+        )
+    };
+    match api_error::result_from(ret) {
+        Ok(()) => runtime::revert(ApiError::User(Error::ReadMintLocalState as u16)),
+        Err(ApiError::ValueNotFound) => (),
+        Err(error) => runtime::revert(error),
+    }
+}
@@ -0,0 +1,63 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{format, string::String};
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, U512};
+
+const DICTIONARY_NAME: &str = "dictionary";
+const ENTRY_COUNT: u64 = 100;
+const COMMAND_PUT: &str = "put";
+const COMMAND_GET: &str = "get";
+
+#[repr(u16)]
+enum Error {
+    MissingDictionary = 0,
+    MissingEntry,
+    UnexpectedEntry,
+    UnexpectedValue,
+}
+
+fn entry_key(index: u64) -> String {
+    format!("entry-{}", index)
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let command: String = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    match command.as_str() {
+        COMMAND_PUT => {
+            let dictionary = storage::new_dictionary(DICTIONARY_NAME);
+            for index in 0..ENTRY_COUNT {
+                storage::dictionary_put(dictionary, &entry_key(index), U512::from(index));
+            }
+        }
+        COMMAND_GET => {
+            let dictionary = runtime::get_key_as_uref(DICTIONARY_NAME)
+                .unwrap_or_revert_with(ApiError::User(Error::MissingDictionary as u16));
+            for index in 0..ENTRY_COUNT {
+                let value: U512 = storage::dictionary_get(dictionary, &entry_key(index))
+                    .unwrap_or_revert()
+                    .unwrap_or_revert_with(ApiError::User(Error::MissingEntry as u16));
+                if value != U512::from(index) {
+                    runtime::revert(ApiError::User(Error::UnexpectedValue as u16));
+                }
+            }
+            let missing: Option<U512> =
+                storage::dictionary_get(dictionary, &entry_key(ENTRY_COUNT)).unwrap_or_revert();
+            if missing.is_some() {
+                runtime::revert(ApiError::User(Error::UnexpectedEntry as u16));
+            }
+        }
+        _ => runtime::revert(ApiError::InvalidArgument),
+    }
+}
@@ -0,0 +1,47 @@
+#![no_std]
+#![no_main]
+
+use contract::{
+    contract_api::runtime::{self, ArgError},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, U512};
+
+const VALUE_ARG_INDEX: u32 = 1;
+const EXPECTED_VALUE: u64 = 42;
+const MATCH_ON_ERROR: u8 = 0;
+const UNWRAP_OR_REVERT: u8 = 1;
+
+#[repr(u16)]
+enum Error {
+    Missing = 0,
+    Deserialization,
+    UnexpectedValue,
+    UnknownMode,
+}
+
+impl From<Error> for ApiError {
+    fn from(error: Error) -> Self {
+        ApiError::User(error as u16)
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mode: u8 = runtime::get_arg_checked(0).unwrap_or_revert();
+
+    let value: U512 = match mode {
+        MATCH_ON_ERROR => match runtime::get_arg_checked(VALUE_ARG_INDEX) {
+            Ok(value) => value,
+            Err(ArgError::Missing) => runtime::revert(Error::Missing),
+            Err(ArgError::Deserialization(_)) => runtime::revert(Error::Deserialization),
+            Err(error) => runtime::revert(error),
+        },
+        UNWRAP_OR_REVERT => runtime::get_arg_checked(VALUE_ARG_INDEX).unwrap_or_revert(),
+        _ => runtime::revert(Error::UnknownMode),
+    };
+
+    if value != U512::from(EXPECTED_VALUE) {
+        runtime::revert(Error::UnexpectedValue);
+    }
+}
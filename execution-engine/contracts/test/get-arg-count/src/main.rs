@@ -0,0 +1,13 @@
+#![no_std]
+#![no_main]
+
+use contract::contract_api::{runtime, storage};
+
+const ARG_COUNT_KEY: &str = "arg_count";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let arg_count = runtime::get_arg_count();
+    let uref = storage::new_uref(arg_count);
+    runtime::put_key(ARG_COUNT_KEY, uref.into());
+}
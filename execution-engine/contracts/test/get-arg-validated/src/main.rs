@@ -0,0 +1,21 @@
+#![no_std]
+#![no_main]
+
+use contract::contract_api::runtime;
+use types::{ApiError, U512};
+
+#[repr(u16)]
+enum Error {
+    ZeroAmount = 0,
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let _amount: U512 = runtime::get_arg_validated(0, |amount: &U512| {
+        if amount.is_zero() {
+            Err(ApiError::User(Error::ZeroAmount as u16).into())
+        } else {
+            Ok(())
+        }
+    });
+}
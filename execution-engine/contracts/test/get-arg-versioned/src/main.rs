@@ -0,0 +1,48 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use types::{bytesrepr, ApiError, U512};
+
+const VERSION_1: u8 = 1;
+const VERSION_2: u8 = 2;
+const EXPECTED_AMOUNT: u64 = 42;
+const EXPECTED_MEMO: &str = "Hello, world!";
+
+#[repr(u16)]
+enum Error {
+    MissingArgument = 0,
+    InvalidArgument,
+    UnexpectedAmount,
+    UnexpectedMemo,
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let (version, payload) = runtime::get_arg_versioned(0, &[VERSION_1, VERSION_2])
+        .unwrap_or_revert_with(ApiError::User(Error::MissingArgument as u16))
+        .unwrap_or_revert_with(ApiError::User(Error::InvalidArgument as u16));
+
+    // Version 1 of the argument layout carries only an amount, version 2 adds a memo.
+    let (amount, memo): (U512, String) = match version {
+        VERSION_1 => {
+            let amount = bytesrepr::deserialize(payload)
+                .unwrap_or_revert_with(ApiError::User(Error::InvalidArgument as u16));
+            (amount, String::from(EXPECTED_MEMO))
+        }
+        VERSION_2 => bytesrepr::deserialize(payload)
+            .unwrap_or_revert_with(ApiError::User(Error::InvalidArgument as u16)),
+        _ => runtime::revert(ApiError::User(Error::InvalidArgument as u16)),
+    };
+
+    if amount != U512::from(EXPECTED_AMOUNT) {
+        runtime::revert(ApiError::User(Error::UnexpectedAmount as u16));
+    }
+    if memo != EXPECTED_MEMO {
+        runtime::revert(ApiError::User(Error::UnexpectedMemo as u16));
+    }
+}
@@ -0,0 +1,85 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, string::String, vec, vec::Vec};
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{account::PublicKey, ApiError, CLValue, CallStackElement, Key};
+
+const INNER_EXT: &str = "inner_ext";
+const OUTER_EXT: &str = "outer_ext";
+const INNER_KEY_NAME: &str = "inner";
+
+/// The originating caller, immediate caller and call stack as seen by a contract.
+type CallerView = (PublicKey, Option<CallStackElement>, Vec<CallStackElement>);
+
+fn caller_view() -> CallerView {
+    (
+        runtime::get_caller(),
+        runtime::get_immediate_caller(),
+        runtime::get_call_stack(),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn inner_ext() {
+    let return_value = CLValue::from_t(caller_view()).unwrap_or_revert();
+    runtime::ret(return_value)
+}
+
+#[no_mangle]
+pub extern "C" fn outer_ext() {
+    let inner = runtime::get_key(INNER_KEY_NAME)
+        .and_then(Key::to_contract_ref)
+        .unwrap_or_revert_with(ApiError::GetKey);
+    let inner_view: CallerView = runtime::call_contract(inner, ());
+    let return_value = CLValue::from_t((inner_view, caller_view())).unwrap_or_revert();
+    runtime::ret(return_value)
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let known_public_key: PublicKey = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let session = CallStackElement::Session(known_public_key);
+
+    // With a call stack of depth one, there is no immediate caller.
+    assert_eq!(
+        caller_view(),
+        (known_public_key, None, vec![session]),
+        "unexpected caller view from session"
+    );
+
+    let inner = storage::store_function_at_hash(INNER_EXT, BTreeMap::new());
+    let inner_element = CallStackElement::StoredContract(Key::from(inner.clone()));
+    let mut outer_named_keys = BTreeMap::new();
+    outer_named_keys.insert(String::from(INNER_KEY_NAME), Key::from(inner));
+    let outer = storage::store_function_at_hash(OUTER_EXT, outer_named_keys);
+    let outer_element = CallStackElement::StoredContract(Key::from(outer.clone()));
+
+    let (inner_view, outer_view): (CallerView, CallerView) = runtime::call_contract(outer, ());
+    assert_eq!(
+        outer_view,
+        (
+            known_public_key,
+            Some(session),
+            vec![session, outer_element]
+        ),
+        "unexpected caller view from contract called by session"
+    );
+    assert_eq!(
+        inner_view,
+        (
+            known_public_key,
+            Some(outer_element),
+            vec![session, outer_element, inner_element]
+        ),
+        "unexpected caller view from contract called by contract"
+    );
+}
@@ -0,0 +1,31 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{format, string::String};
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{account::PublicKey, bytesrepr::FromBytes, CLTyped, U512};
+
+/// Reads the i-th argument and stores it under the named key "arg<i>".
+fn store_arg<T: FromBytes + CLTyped>(i: u32) {
+    let value: T = runtime::get_arg_checked(i).unwrap_or_revert();
+    let uref = storage::new_uref(value);
+    runtime::put_key(&format!("arg{}", i), uref.into());
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    store_arg::<String>(0);
+    store_arg::<U512>(1);
+    store_arg::<u8>(2);
+    store_arg::<u32>(3);
+    store_arg::<u64>(4);
+    store_arg::<i32>(5);
+    store_arg::<PublicKey>(6);
+    store_arg::<bool>(7);
+}
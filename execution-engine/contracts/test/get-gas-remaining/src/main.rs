@@ -0,0 +1,27 @@
+#![no_std]
+#![no_main]
+
+use contract::{
+    contract_api::{account, runtime},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, U512};
+
+#[repr(u16)]
+enum Error {
+    GasLow = 0,
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let gas_threshold: U512 = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    loop {
+        if runtime::get_gas_remaining() < gas_threshold {
+            runtime::revert(ApiError::User(Error::GasLow as u16));
+        }
+        let _main_purse = account::get_main_purse();
+    }
+}
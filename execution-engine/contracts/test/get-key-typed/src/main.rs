@@ -0,0 +1,59 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, Key};
+
+const UREF_NAME: &str = "uref";
+const HASH_NAME: &str = "hash";
+const HASH_VALUE: [u8; 32] = [7u8; 32];
+const COMMAND_UREF: &str = "uref";
+const COMMAND_HASH: &str = "hash";
+const COMMAND_MISMATCH: &str = "mismatch";
+
+#[repr(u16)]
+enum Error {
+    UnknownCommand = 0,
+    MissingKey,
+    UnexpectedValue,
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let command: String = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    let uref = storage::new_uref(());
+    runtime::put_key(UREF_NAME, uref.into());
+    runtime::put_key(HASH_NAME, Key::Hash(HASH_VALUE));
+
+    match command.as_str() {
+        COMMAND_UREF => {
+            let stored_uref = runtime::get_key_as_uref(UREF_NAME)
+                .unwrap_or_revert_with(ApiError::User(Error::MissingKey as u16));
+            if stored_uref != uref {
+                runtime::revert(ApiError::User(Error::UnexpectedValue as u16));
+            }
+        }
+        COMMAND_HASH => {
+            let stored_hash = runtime::get_key_as_hash(HASH_NAME)
+                .unwrap_or_revert_with(ApiError::User(Error::MissingKey as u16));
+            if stored_hash != HASH_VALUE {
+                runtime::revert(ApiError::User(Error::UnexpectedValue as u16));
+            }
+        }
+        COMMAND_MISMATCH => {
+            // Expected to revert with `ApiError::UnexpectedKeyVariant`.
+            runtime::get_key_as_uref(HASH_NAME);
+        }
+        _ => runtime::revert(ApiError::User(Error::UnknownCommand as u16)),
+    }
+}
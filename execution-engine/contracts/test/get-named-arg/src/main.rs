@@ -0,0 +1,59 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use types::{ApiError, U512};
+
+const AMOUNT_ARG_NAME: &str = "amount";
+const MEMO_ARG_NAME: &str = "memo";
+const ABSENT_ARG_NAME: &str = "absent";
+const POSITIONAL_ARG_INDEX: u32 = 1;
+const EXPECTED_AMOUNT: u64 = 42;
+const EXPECTED_MEMO: &str = "Hello, world!";
+const EXPECTED_POSITIONAL: u8 = 7;
+
+#[repr(u16)]
+enum Error {
+    MissingArgument = 0,
+    InvalidArgument,
+    UnexpectedAmount,
+    UnexpectedMemo,
+    UnexpectedPositional,
+    UnexpectedNamedArg,
+}
+
+impl From<Error> for ApiError {
+    fn from(error: Error) -> Self {
+        ApiError::User(error as u16)
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let amount: U512 = runtime::get_named_arg(AMOUNT_ARG_NAME)
+        .unwrap_or_revert_with(Error::MissingArgument)
+        .unwrap_or_revert_with(Error::InvalidArgument);
+    let memo: String = runtime::get_named_arg(MEMO_ARG_NAME)
+        .unwrap_or_revert_with(Error::MissingArgument)
+        .unwrap_or_revert_with(Error::InvalidArgument);
+    let positional: u8 = runtime::get_arg(POSITIONAL_ARG_INDEX)
+        .unwrap_or_revert_with(Error::MissingArgument)
+        .unwrap_or_revert_with(Error::InvalidArgument);
+
+    if amount != U512::from(EXPECTED_AMOUNT) {
+        runtime::revert(Error::UnexpectedAmount);
+    }
+    if memo != EXPECTED_MEMO {
+        runtime::revert(Error::UnexpectedMemo);
+    }
+    if positional != EXPECTED_POSITIONAL {
+        runtime::revert(Error::UnexpectedPositional);
+    }
+    if runtime::get_named_arg::<U512>(ABSENT_ARG_NAME).is_some() {
+        runtime::revert(Error::UnexpectedNamedArg);
+    }
+}
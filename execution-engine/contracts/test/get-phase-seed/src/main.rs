@@ -0,0 +1,13 @@
+#![no_std]
+#![no_main]
+
+use contract::contract_api::{runtime, storage};
+
+const SEED_NAME: &str = "seed";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let seed = runtime::get_phase_seed();
+    let seed_uref = storage::new_uref(seed);
+    runtime::put_key(SEED_NAME, seed_uref.into());
+}
@@ -0,0 +1,13 @@
+#![no_std]
+#![no_main]
+
+use contract::contract_api::{runtime, storage};
+
+const TOTAL_ARGS_SIZE_KEY: &str = "total_args_size";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let total_args_size = runtime::total_args_size() as u64;
+    let uref = storage::new_uref(total_args_size);
+    runtime::put_key(TOTAL_ARGS_SIZE_KEY, uref.into());
+}
@@ -25,6 +25,8 @@ const COMMAND_TEST_READ_UREF1: &str = "test-read-uref1";
 const COMMAND_TEST_READ_UREF2: &str = "test-read-uref2";
 const COMMAND_INCREASE_UREF2: &str = "increase-uref2";
 const COMMAND_OVERWRITE_UREF2: &str = "overwrite-uref2";
+const COMMAND_TEST_HAS_UREF1: &str = "test-has-uref1";
+const COMMAND_TEST_HAS_NO_UREF1: &str = "test-has-no-uref1";
 
 #[no_mangle]
 pub extern "C" fn call() {
@@ -86,6 +88,16 @@ pub extern "C" fn call() {
             let new_value = storage::read(big_value_ref);
             assert_eq!(new_value, Ok(Some(U512::from(123_456_789u64))));
         }
+        COMMAND_TEST_HAS_UREF1 => {
+            if !runtime::has_key("hello-world") {
+                runtime::revert(ApiError::User(103))
+            }
+        }
+        COMMAND_TEST_HAS_NO_UREF1 => {
+            if runtime::has_key("hello-world") {
+                runtime::revert(ApiError::User(104))
+            }
+        }
         _ => runtime::revert(ApiError::InvalidArgument),
     }
 }
@@ -0,0 +1,29 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+
+use contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use types::ApiError;
+
+const REVERT_ERROR: ApiError = ApiError::User(0);
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let lines: Vec<String> = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let should_revert: bool = runtime::get_arg(1)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    for line in &lines {
+        runtime::print(line);
+    }
+
+    if should_revert {
+        runtime::revert(REVERT_ERROR);
+    }
+}
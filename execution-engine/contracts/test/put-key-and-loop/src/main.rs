@@ -0,0 +1,16 @@
+#![no_std]
+#![no_main]
+
+use contract::contract_api::{account, runtime, storage};
+
+const MARKER_KEY_NAME: &str = "marker";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let marker = storage::new_uref(());
+    runtime::put_key(MARKER_KEY_NAME, marker.into());
+
+    loop {
+        let _main_purse = account::get_main_purse();
+    }
+}
@@ -0,0 +1,29 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, string::String};
+
+use contract::contract_api::{runtime, storage};
+use types::Key;
+
+const VALUE_KEY: &str = "value";
+const CONTRACT_KEY: &str = "contract";
+const VALUE: &str = "Hello, world!";
+const ENTRY_FUNCTION_NAME: &str = "do_nothing";
+
+#[no_mangle]
+pub extern "C" fn do_nothing() {}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let value_key: Key = storage::new_uref(String::from(VALUE)).into();
+
+    let mut named_keys = BTreeMap::new();
+    named_keys.insert(String::from(VALUE_KEY), value_key);
+    let contract_key: Key = storage::store_function_at_hash(ENTRY_FUNCTION_NAME, named_keys).into();
+
+    runtime::put_key(VALUE_KEY, value_key);
+    runtime::put_key(CONTRACT_KEY, contract_key);
+}
@@ -0,0 +1,26 @@
+#![no_std]
+#![no_main]
+
+use contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use types::{
+    account::{PublicKey, ED25519_LENGTH},
+    ApiError,
+};
+
+#[repr(u16)]
+enum Error {
+    UnexpectedLength = 0,
+    UnexpectedPublicKey,
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut buf = [0u8; ED25519_LENGTH];
+    let written = runtime::read_arg_into(0, &mut buf).unwrap_or_revert();
+    if written != ED25519_LENGTH {
+        runtime::revert(ApiError::User(Error::UnexpectedLength as u16));
+    }
+    if PublicKey::ed25519_from(buf) != runtime::get_caller() {
+        runtime::revert(ApiError::User(Error::UnexpectedPublicKey as u16));
+    }
+}
@@ -0,0 +1,48 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, string::String};
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, Key, URef};
+
+const COUNTER_KEY: &str = "counter";
+const ENTRY_FUNCTION_NAME: &str = "increment";
+
+#[repr(u16)]
+enum Error {
+    UnexpectedCounterValue = 0,
+}
+
+fn get_counter_uref() -> URef {
+    runtime::get_key_as_uref(COUNTER_KEY).unwrap_or_revert_with(ApiError::GetKey)
+}
+
+#[no_mangle]
+pub extern "C" fn increment() {
+    storage::add(get_counter_uref(), 1i32);
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let counter_uref = storage::new_uref(0i32);
+    let counter_key: Key = counter_uref.into();
+
+    let mut named_keys: BTreeMap<String, Key> = BTreeMap::new();
+    named_keys.insert(String::from(COUNTER_KEY), counter_key);
+    let contract_ref = storage::store_function(ENTRY_FUNCTION_NAME, named_keys);
+
+    // Call the contract in the same deploy in which it was stored.
+    runtime::call_contract::<_, ()>(contract_ref, ());
+
+    let counter: i32 = storage::read_or_revert(counter_uref);
+    if counter != 1 {
+        runtime::revert(ApiError::User(Error::UnexpectedCounterValue as u16));
+    }
+    runtime::put_key(COUNTER_KEY, counter_key);
+}
@@ -0,0 +1,44 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::format;
+
+use contract::{
+    contract_api::{account, runtime, storage, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{account::PublicKey, ApiError, U512};
+
+const TRANSFER_RESULT_NAME: &str = "transfer_result";
+const BALANCE_BEFORE_NAME: &str = "balance_before";
+const BALANCE_AFTER_NAME: &str = "balance_after";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let target: PublicKey = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let amount: U512 = runtime::get_arg(1)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    let main_purse = account::get_main_purse();
+    let balance_before = system::get_balance(main_purse).unwrap_or_revert();
+
+    // A failed transfer is recorded rather than reverted, so the test can inspect it.
+    let transfer_result = system::transfer_to_account(target, amount);
+
+    let balance_after = system::get_balance(main_purse).unwrap_or_revert();
+
+    runtime::put_key(
+        TRANSFER_RESULT_NAME,
+        storage::new_uref(format!("{:?}", transfer_result)).into(),
+    );
+    runtime::put_key(
+        BALANCE_BEFORE_NAME,
+        storage::new_uref(balance_before).into(),
+    );
+    runtime::put_key(BALANCE_AFTER_NAME, storage::new_uref(balance_after).into());
+}
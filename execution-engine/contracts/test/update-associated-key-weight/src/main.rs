@@ -0,0 +1,37 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use contract::{
+    contract_api::{account, runtime},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{
+    account::{ActionType, PublicKey, Weight},
+    ApiError,
+};
+
+const ASSOCIATED_KEY: PublicKey = PublicKey::ed25519_from([42; 32]);
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let stage: String = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    if stage == "raise" {
+        account::add_associated_key(ASSOCIATED_KEY, Weight::new(1)).unwrap_or_revert();
+        account::update_associated_key(ASSOCIATED_KEY, Weight::new(3)).unwrap_or_revert();
+    } else if stage == "lower-below-threshold" {
+        // Total weight of identity key and associated key is 4, which exactly meets the threshold.
+        account::add_associated_key(ASSOCIATED_KEY, Weight::new(3)).unwrap_or_revert();
+        account::set_action_threshold(ActionType::KeyManagement, Weight::new(4)).unwrap_or_revert();
+        // Lowering the associated key's weight would leave the total below the threshold.
+        account::update_associated_key(ASSOCIATED_KEY, Weight::new(1)).unwrap_or_revert();
+    } else {
+        runtime::revert(ApiError::InvalidArgument)
+    }
+}
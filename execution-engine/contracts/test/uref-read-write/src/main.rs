@@ -0,0 +1,49 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::ApiError;
+
+const COMMAND_CYCLE: &str = "cycle";
+const COMMAND_WRITE_READ_ONLY: &str = "write-read-only";
+const INITIAL_VALUE: u64 = 1;
+const UPDATED_VALUE: u64 = 2;
+
+#[repr(u16)]
+enum Error {
+    UnexpectedInitialValue = 0,
+    UnexpectedUpdatedValue,
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let command: String = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    let uref = storage::new_uref(INITIAL_VALUE);
+
+    match command.as_str() {
+        COMMAND_CYCLE => {
+            let value: u64 = storage::read_or_revert(uref);
+            if value != INITIAL_VALUE {
+                runtime::revert(ApiError::User(Error::UnexpectedInitialValue as u16));
+            }
+            storage::write(uref, UPDATED_VALUE);
+            let value: u64 = storage::read_or_revert(uref);
+            if value != UPDATED_VALUE {
+                runtime::revert(ApiError::User(Error::UnexpectedUpdatedValue as u16));
+            }
+        }
+        // The host should refuse this write, as the attenuated uref lacks write rights.
+        COMMAND_WRITE_READ_ONLY => storage::write(uref.into_read(), UPDATED_VALUE),
+        _ => runtime::revert(ApiError::InvalidArgument),
+    }
+}
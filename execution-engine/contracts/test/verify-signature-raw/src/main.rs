@@ -0,0 +1,43 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use contract::{contract_api::runtime, ext_ffi, unwrap_or_revert::UnwrapOrRevert};
+use types::ApiError;
+
+#[repr(u16)]
+enum Error {
+    InvalidSignature = 0,
+}
+
+fn get_bytes_arg(index: u32) -> Vec<u8> {
+    runtime::get_arg(index)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument)
+}
+
+/// Passes the raw public key and signature bytes to the host unchecked, so that malformed or
+/// wrongly-sized inputs which `runtime::verify_signature` can't express reach the host as-is.
+#[no_mangle]
+pub extern "C" fn call() {
+    let public_key_bytes = get_bytes_arg(0);
+    let message = get_bytes_arg(1);
+    let signature_bytes = get_bytes_arg(2);
+
+    let result = unsafe {
+        ext_ffi::verify_signature(
+            public_key_bytes.as_ptr(),
+            public_key_bytes.len(),
+            message.as_ptr(),
+            message.len(),
+            signature_bytes.as_ptr(),
+            signature_bytes.len(),
+        )
+    };
+    if result == 0 {
+        runtime::revert(ApiError::User(Error::InvalidSignature as u16));
+    }
+}
@@ -0,0 +1,34 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use types::{
+    account::{PublicKey, Signature},
+    ApiError,
+};
+
+#[repr(u16)]
+enum Error {
+    InvalidSignature = 0,
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let public_key: PublicKey = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let message: Vec<u8> = runtime::get_arg(1)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let signature: Signature = runtime::get_arg(2)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    if !runtime::verify_signature(public_key, &message, &signature) {
+        runtime::revert(ApiError::User(Error::InvalidSignature as u16));
+    }
+}
@@ -4,6 +4,8 @@ pub struct EngineConfig {
     // feature flags go here
     use_system_contracts: bool,
     enable_bonding: bool,
+    max_args_bytes: Option<usize>,
+    get_arg_cost_per_byte: u64,
 }
 
 impl EngineConfig {
@@ -29,4 +31,26 @@ impl EngineConfig {
         self.enable_bonding = enable_bonding;
         self
     }
+
+    /// The maximum serialized size in bytes of the session or payment arguments of a deploy, or
+    /// `None` if argument payloads are unbounded.
+    pub fn max_args_bytes(self) -> Option<usize> {
+        self.max_args_bytes
+    }
+
+    pub fn with_max_args_bytes(mut self, max_args_bytes: Option<usize>) -> EngineConfig {
+        self.max_args_bytes = max_args_bytes;
+        self
+    }
+
+    /// The gas charged per byte of an argument copied into Wasm memory by `get_arg`.  Defaults to
+    /// zero, so deploys are only charged for copying arguments when this is explicitly configured.
+    pub fn get_arg_cost_per_byte(self) -> u64 {
+        self.get_arg_cost_per_byte
+    }
+
+    pub fn with_get_arg_cost_per_byte(mut self, get_arg_cost_per_byte: u64) -> EngineConfig {
+        self.get_arg_cost_per_byte = get_arg_cost_per_byte;
+        self
+    }
 }
@@ -14,6 +14,11 @@ pub enum Error {
     InvalidPublicKeyLength { expected: usize, actual: usize },
     #[fail(display = "Invalid protocol version: {}", _0)]
     InvalidProtocolVersion(ProtocolVersion),
+    #[fail(
+        display = "Deploy arguments too large: maximum {} bytes, actual {} bytes",
+        max, actual
+    )]
+    ArgsTooLarge { max: usize, actual: usize },
     #[fail(display = "Invalid upgrade config")]
     InvalidUpgradeConfig,
     #[fail(display = "Wasm preprocessing error: {}", _0)]
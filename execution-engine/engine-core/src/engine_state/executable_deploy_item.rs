@@ -19,6 +19,15 @@ pub enum ExecutableDeployItem {
 }
 
 impl ExecutableDeployItem {
+    pub fn args(&self) -> &[u8] {
+        match self {
+            ExecutableDeployItem::ModuleBytes { args, .. } => args,
+            ExecutableDeployItem::StoredContractByHash { args, .. } => args,
+            ExecutableDeployItem::StoredContractByName { args, .. } => args,
+            ExecutableDeployItem::StoredContractByURef { args, .. } => args,
+        }
+    }
+
     pub fn take_args(self) -> Vec<u8> {
         match self {
             ExecutableDeployItem::ModuleBytes { args, .. } => args,
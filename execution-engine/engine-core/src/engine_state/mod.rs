@@ -832,6 +832,20 @@ where
         let authorization_keys = deploy_item.authorization_keys;
         let deploy_hash = deploy_item.deploy_hash;
 
+        // Reject oversized argument payloads before doing any work on the deploy
+        if let Some(max) = self.config.max_args_bytes() {
+            for args in &[session.args(), payment.args()] {
+                if args.len() > max {
+                    return Ok(ExecutionResult::precondition_failure(
+                        error::Error::ArgsTooLarge {
+                            max,
+                            actual: args.len(),
+                        },
+                    ));
+                }
+            }
+        }
+
         // Create tracking copy (which functions as a deploy context)
         // validation_spec_2: prestate_hash check
         let tracking_copy = match self.tracking_copy(prestate_hash) {
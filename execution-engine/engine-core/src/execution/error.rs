@@ -1,3 +1,5 @@
+use std::fmt::{self, Display, Formatter};
+
 use failure::Fail;
 use parity_wasm::elements;
 
@@ -44,6 +46,12 @@ pub enum Error {
     /// Reverts execution with a provided status
     #[fail(display = "{}", _0)]
     Revert(ApiError),
+    /// Reverts execution with a provided status and a human-readable message
+    #[fail(display = "{}: {}", error, message)]
+    RevertWithMessage {
+        error: ApiError,
+        message: RevertMessage,
+    },
     #[fail(display = "{}", _0)]
     AddKeyFailure(AddKeyFailure),
     #[fail(display = "{}", _0)]
@@ -75,6 +83,26 @@ pub enum Error {
     UnsupportedWasmStart,
 }
 
+/// The message passed to the host by a contract reverting via `revert_with_message`.
+///
+/// It is displayed with a " (truncated)" suffix if the contract's message was longer than
+/// [`REVERT_MESSAGE_MAX_LENGTH`](types::REVERT_MESSAGE_MAX_LENGTH) bytes and had to be cut short.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RevertMessage {
+    pub text: String,
+    pub truncated: bool,
+}
+
+impl Display for RevertMessage {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.text)?;
+        if self.truncated {
+            write!(f, " (truncated)")?;
+        }
+        Ok(())
+    }
+}
+
 impl wasmi::HostError for Error {}
 
 impl From<wasmi::Error> for Error {
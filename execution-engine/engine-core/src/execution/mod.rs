@@ -7,7 +7,7 @@ mod tests;
 
 pub use self::{
     address_generator::{AddressGenerator, AddressGeneratorBuilder},
-    error::Error,
+    error::{Error, RevertMessage},
     executor::Executor,
 };
 
@@ -43,6 +43,16 @@ pub enum FunctionIndex {
     GetMainPurseIndex,
     GetArgSizeFuncIndex,
     ReadHostBufferIndex,
+    GetTotalArgsSizeFuncIndex,
+    VerifySignatureFuncIndex,
+    GetGasRemainingIndex,
+    GetArgCountFuncIndex,
+    RevertWithMessageFuncIndex,
+    GetCallStackFuncIndex,
+    Blake2bFuncIndex,
+    DictionaryGetFuncIndex,
+    DictionaryPutFuncIndex,
+    GetPhaseSeedIndex,
     #[cfg(feature = "test-support")]
     PrintIndex,
 }
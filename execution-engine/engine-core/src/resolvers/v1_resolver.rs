@@ -188,6 +188,46 @@ impl ModuleImportResolver for RuntimeModuleImportResolver {
                 Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32)),
                 FunctionIndex::ReadHostBufferIndex.into(),
             ),
+            "get_total_args_size" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 1][..], Some(ValueType::I32)),
+                FunctionIndex::GetTotalArgsSizeFuncIndex.into(),
+            ),
+            "verify_signature" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 6][..], Some(ValueType::I32)),
+                FunctionIndex::VerifySignatureFuncIndex.into(),
+            ),
+            "get_gas_remaining" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 1][..], Some(ValueType::I32)),
+                FunctionIndex::GetGasRemainingIndex.into(),
+            ),
+            "get_arg_count" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 1][..], Some(ValueType::I32)),
+                FunctionIndex::GetArgCountFuncIndex.into(),
+            ),
+            "revert_with_message" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 3][..], None),
+                FunctionIndex::RevertWithMessageFuncIndex.into(),
+            ),
+            "get_call_stack" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 1][..], Some(ValueType::I32)),
+                FunctionIndex::GetCallStackFuncIndex.into(),
+            ),
+            "blake2b" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 4][..], Some(ValueType::I32)),
+                FunctionIndex::Blake2bFuncIndex.into(),
+            ),
+            "dictionary_get" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 5][..], Some(ValueType::I32)),
+                FunctionIndex::DictionaryGetFuncIndex.into(),
+            ),
+            "dictionary_put" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 6][..], None),
+                FunctionIndex::DictionaryPutFuncIndex.into(),
+            ),
+            "get_phase_seed" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 1][..], None),
+                FunctionIndex::GetPhaseSeedIndex.into(),
+            ),
             #[cfg(feature = "test-support")]
             "print" => FuncInstance::alloc_host(
                 Signature::new(&[ValueType::I32; 2][..], None),
@@ -496,6 +496,125 @@ where
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
 
+            FunctionIndex::GetTotalArgsSizeFuncIndex => {
+                // args(0) = pointer to the total size of all arguments (output)
+                let size_ptr: u32 = Args::parse(args)?;
+                let ret = self.get_total_args_size(size_ptr)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::VerifySignatureFuncIndex => {
+                // args(0) = pointer to public key in Wasm memory
+                // args(1) = size of public key
+                // args(2) = pointer to message in Wasm memory
+                // args(3) = size of message
+                // args(4) = pointer to signature in Wasm memory
+                // args(5) = size of signature
+                let (
+                    public_key_ptr,
+                    public_key_size,
+                    message_ptr,
+                    message_size,
+                    signature_ptr,
+                    signature_size,
+                ): (_, _, _, u32, _, _) = Args::parse(args)?;
+                scoped_instrumenter.add_property("message_size", message_size);
+                let verified = self.verify_signature(
+                    public_key_ptr,
+                    public_key_size,
+                    message_ptr,
+                    message_size,
+                    signature_ptr,
+                    signature_size,
+                )?;
+                Ok(Some(RuntimeValue::I32(i32::from(verified))))
+            }
+
+            FunctionIndex::GetGasRemainingIndex => {
+                // args(0) = pointer where a size of serialized bytes will be stored
+                let output_size = Args::parse(args)?;
+                let ret = self.get_gas_remaining(output_size)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::GetArgCountFuncIndex => {
+                // args(0) = pointer to the number of arguments (output)
+                let count_ptr: u32 = Args::parse(args)?;
+                let ret = self.get_arg_count(count_ptr)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::RevertWithMessageFuncIndex => {
+                // args(0) = status u32
+                // args(1) = pointer to serialized message
+                // args(2) = size of serialized message
+                let (status, message_ptr, message_size) = Args::parse(args)?;
+                scoped_instrumenter.add_property("message_size", message_size);
+                Err(self.revert_with_message(status, message_ptr, message_size))
+            }
+
+            FunctionIndex::GetCallStackFuncIndex => {
+                // args(0) = pointer where a size of serialized bytes will be stored
+                let output_size = Args::parse(args)?;
+                let ret = self.get_call_stack(output_size)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::Blake2bFuncIndex => {
+                // args(0) = pointer to input bytes in wasm memory
+                // args(1) = size of input bytes
+                // args(2) = pointer to the output buffer in wasm memory
+                // args(3) = size of the output buffer
+                let (in_ptr, in_size, out_ptr, out_size) = Args::parse(args)?;
+                scoped_instrumenter.add_property("in_size", in_size);
+                let ret = self.blake2b(in_ptr, in_size, out_ptr, out_size)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::DictionaryGetFuncIndex => {
+                // args(0) = pointer to dictionary uref in Wasm memory
+                // args(1) = size of dictionary uref
+                // args(2) = pointer to key bytes in Wasm memory
+                // args(3) = size of key bytes
+                // args(4) = pointer to output size (output param)
+                let (uref_ptr, uref_size, key_ptr, key_size, output_size_ptr): (_, _, _, u32, _) =
+                    Args::parse(args)?;
+                scoped_instrumenter.add_property("key_size", key_size);
+                let ret =
+                    self.dictionary_get(uref_ptr, uref_size, key_ptr, key_size, output_size_ptr)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::DictionaryPutFuncIndex => {
+                // args(0) = pointer to dictionary uref in Wasm memory
+                // args(1) = size of dictionary uref
+                // args(2) = pointer to key bytes in Wasm memory
+                // args(3) = size of key bytes
+                // args(4) = pointer to value
+                // args(5) = size of value
+                let (uref_ptr, uref_size, key_ptr, key_size, value_ptr, value_size): (
+                    _,
+                    _,
+                    _,
+                    u32,
+                    _,
+                    u32,
+                ) = Args::parse(args)?;
+                scoped_instrumenter.add_property("key_size", key_size);
+                scoped_instrumenter.add_property("value_size", value_size);
+                self.dictionary_put(
+                    uref_ptr, uref_size, key_ptr, key_size, value_ptr, value_size,
+                )?;
+                Ok(None)
+            }
+
+            FunctionIndex::GetPhaseSeedIndex => {
+                // args(0) = pointer to Wasm memory where to write.
+                let dest_ptr = Args::parse(args)?;
+                self.get_phase_seed(dest_ptr)?;
+                Ok(None)
+            }
+
             #[cfg(feature = "test-support")]
             FunctionIndex::PrintIndex => {
                 let (text_ptr, text_size): (_, u32) = Args::parse(args)?;
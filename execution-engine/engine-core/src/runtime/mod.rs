@@ -18,28 +18,48 @@ use wasmi::{ImportsBuilder, MemoryRef, ModuleInstance, ModuleRef, Trap, TrapKind
 
 use ::mint::Mint;
 use contract::args_parser::ArgsParser;
-use engine_shared::{account::Account, contract::Contract, gas::Gas, stored_value::StoredValue};
+use engine_shared::{
+    account::Account, contract::Contract, gas::Gas, newtypes::Blake2bHash,
+    stored_value::StoredValue,
+};
 use engine_storage::{global_state::StateReader, protocol_data::ProtocolData};
 use proof_of_stake::ProofOfStake;
 use standard_payment::StandardPayment;
 use types::{
-    account::{ActionType, PublicKey, Weight},
-    bytesrepr::{self, FromBytes, ToBytes},
+    account::{ActionType, PublicKey, Signature, Weight},
+    bytesrepr::{self, FromBytes, ToBytes, U32_SERIALIZED_LENGTH},
     system_contract_errors,
     system_contract_errors::mint,
-    AccessRights, ApiError, CLType, CLTyped, CLValue, Key, ProtocolVersion, SystemContractType,
-    TransferResult, TransferredTo, URef, U128, U256, U512,
+    AccessRights, ApiError, CLType, CLTyped, CLValue, CallStackElement, Key, ProtocolVersion,
+    SystemContractType, TransferResult, TransferredTo, URef, BLAKE2B_DIGEST_LENGTH,
+    REVERT_MESSAGE_MAX_LENGTH, U128, U256, U512,
 };
 
 use crate::{
     engine_state::{system_contract_cache::SystemContractCache, EngineConfig},
-    execution::{Error, MINT_NAME, POS_NAME},
+    execution::{Error, RevertMessage, MINT_NAME, POS_NAME},
     resolvers::{create_module_resolver, memory_resolver::MemoryResolver},
     runtime_context::RuntimeContext,
     Address,
 };
 use scoped_instrumenter::ScopedInstrumenter;
 
+/// The gas charged per input byte by the `blake2b` host function.
+pub const BLAKE2B_COST_PER_BYTE: u64 = 1;
+
+#[cfg(feature = "test-support")]
+thread_local! {
+    /// Lines printed via the `print` host function on the current thread, not yet taken.
+    static PRINTED_LINES: std::cell::RefCell<Vec<String>> = std::cell::RefCell::new(Vec::new());
+}
+
+#[cfg(feature = "test-support")]
+/// Returns all lines printed via the `print` host function on the current thread since the last
+/// call, and clears them.
+pub fn take_printed_lines() -> Vec<String> {
+    PRINTED_LINES.with(|lines| lines.replace(Vec::new()))
+}
+
 pub struct Runtime<'a, R> {
     system_contract_cache: SystemContractCache,
     config: EngineConfig,
@@ -47,6 +67,7 @@ pub struct Runtime<'a, R> {
     module: Module,
     host_buffer: Option<CLValue>,
     context: RuntimeContext<'a, R>,
+    call_stack: Vec<CallStackElement>,
 }
 
 /// Rename function called `name` in the `module` to `call`.
@@ -1353,6 +1374,10 @@ where
         module: Module,
         context: RuntimeContext<'a, R>,
     ) -> Self {
+        let call_stack = match context.base_key() {
+            Key::Account(public_key) => vec![CallStackElement::Session(public_key)],
+            base_key => vec![CallStackElement::StoredContract(base_key)],
+        };
         Runtime {
             config,
             system_contract_cache,
@@ -1360,6 +1385,7 @@ where
             module,
             host_buffer: None,
             context,
+            call_stack,
         }
     }
 
@@ -1415,6 +1441,12 @@ where
         bytesrepr::deserialize(bytes).map_err(Into::into)
     }
 
+    /// Reads `URef` (defined as `uref_ptr` and `uref_size` tuple) from Wasm memory.
+    fn uref_from_mem(&mut self, uref_ptr: u32, uref_size: u32) -> Result<URef, Error> {
+        let bytes = self.bytes_from_mem(uref_ptr, uref_size as usize)?;
+        bytesrepr::deserialize(bytes).map_err(Into::into)
+    }
+
     /// Reads `CLValue` (defined as `cl_value_ptr` and `cl_value_size` tuple) from Wasm memory.
     fn cl_value_from_mem(
         &mut self,
@@ -1463,6 +1495,39 @@ where
         Ok(self.context.validate_uref(&uref).is_ok())
     }
 
+    /// Verifies that `signature` is a valid Ed25519 signature of `message` made with the secret
+    /// key corresponding to `public_key`.
+    ///
+    /// A signature which fails to parse as an Ed25519 signature is reported as invalid rather than
+    /// as an error.
+    fn verify_signature(
+        &mut self,
+        public_key_ptr: u32,
+        public_key_size: u32,
+        message_ptr: u32,
+        message_size: u32,
+        signature_ptr: u32,
+        signature_size: u32,
+    ) -> Result<bool, Trap> {
+        let public_key: PublicKey = {
+            let bytes = self.bytes_from_mem(public_key_ptr, public_key_size as usize)?;
+            bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+        };
+        let message = self.bytes_from_mem(message_ptr, message_size as usize)?;
+        let signature: Signature = {
+            let bytes = self.bytes_from_mem(signature_ptr, signature_size as usize)?;
+            bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+        };
+
+        let verified = ed25519_dalek::PublicKey::from_bytes(public_key.as_bytes())
+            .and_then(|public_key| {
+                let signature = ed25519_dalek::Signature::from_bytes(signature.as_bytes())?;
+                public_key.verify(&message, &signature)
+            })
+            .is_ok();
+        Ok(verified)
+    }
+
     fn get_arg_size(&mut self, index: usize, size_ptr: u32) -> Result<Result<(), ApiError>, Trap> {
         let arg_size = match self.context.args().get(index) {
             Some(arg) if arg.inner_bytes().len() > u32::max_value() as usize => {
@@ -1481,21 +1546,60 @@ where
         Ok(Ok(()))
     }
 
+    /// Writes the serialized size of the whole argument list passed to the current module into
+    /// `size_ptr`.
+    fn get_total_args_size(&mut self, size_ptr: u32) -> Result<Result<(), ApiError>, Trap> {
+        let total_size = self.context.args().serialized_length();
+        if total_size > u32::max_value() as usize {
+            return Ok(Err(ApiError::OutOfMemory));
+        }
+
+        let total_size_bytes = (total_size as u32).to_le_bytes(); // Wasm is little-endian
+
+        if let Err(e) = self.memory.set(size_ptr, &total_size_bytes) {
+            return Err(Error::Interpreter(e.into()).into());
+        }
+
+        Ok(Ok(()))
+    }
+
+    /// Writes the number of arguments passed to the current module into `count_ptr`.
+    fn get_arg_count(&mut self, count_ptr: u32) -> Result<Result<(), ApiError>, Trap> {
+        let count = self.context.args().len();
+        if count > u32::max_value() as usize {
+            return Ok(Err(ApiError::OutOfMemory));
+        }
+
+        let count_bytes = (count as u32).to_le_bytes(); // Wasm is little-endian
+
+        if let Err(e) = self.memory.set(count_ptr, &count_bytes) {
+            return Err(Error::Interpreter(e.into()).into());
+        }
+
+        Ok(Ok(()))
+    }
+
+    /// Copies the argument at `index` into the output buffer, charging the configured
+    /// [`EngineConfig::get_arg_cost_per_byte`] gas per byte copied.
     fn get_arg(
         &mut self,
         index: usize,
         output_ptr: u32,
         output_size: usize,
     ) -> Result<Result<(), ApiError>, Trap> {
-        let arg = match self.context.args().get(index) {
-            Some(arg) => arg,
+        let arg_size = match self.context.args().get(index) {
+            Some(arg) => arg.inner_bytes().len(),
             None => return Ok(Err(ApiError::MissingArgument)),
         };
 
-        if arg.inner_bytes().len() > output_size {
+        if arg_size > output_size {
             return Ok(Err(ApiError::OutOfMemory));
         }
 
+        let cost = (arg_size as u64).saturating_mul(self.config.get_arg_cost_per_byte());
+        self.gas(Gas::new(cost.into()))?;
+
+        let arg = &self.context.args()[index];
         if let Err(e) = self
             .memory
             .set(output_ptr, &arg.inner_bytes()[..output_size])
@@ -1607,6 +1711,53 @@ where
         Ok(Ok(()))
     }
 
+    /// Writes the amount of gas left before reaching the gas limit of the current deploy into the
+    /// host buffer, and its serialized size into `output_size`.
+    fn get_gas_remaining(&mut self, output_size: u32) -> Result<Result<(), ApiError>, Trap> {
+        if !self.can_write_to_host_buffer() {
+            // Exit early if the host buffer is already occupied
+            return Ok(Err(ApiError::HostBufferFull));
+        }
+        let gas_remaining = self
+            .context
+            .gas_limit()
+            .value()
+            .saturating_sub(self.context.gas_counter().value());
+        let value = CLValue::from_t(gas_remaining).map_err(Error::CLValue)?;
+        let value_size = value.inner_bytes().len() as u32;
+
+        if let Err(error) = self.write_host_buffer(value) {
+            return Ok(Err(error));
+        }
+
+        let output_size_bytes = value_size.to_le_bytes(); // Wasm is little-endian
+        if let Err(error) = self.memory.set(output_size, &output_size_bytes) {
+            return Err(Error::Interpreter(error.into()).into());
+        }
+        Ok(Ok(()))
+    }
+
+    /// Writes the call stack of the current deploy into the host buffer, and its serialized size
+    /// into `output_size`.
+    fn get_call_stack(&mut self, output_size: u32) -> Result<Result<(), ApiError>, Trap> {
+        if !self.can_write_to_host_buffer() {
+            // Exit early if the host buffer is already occupied
+            return Ok(Err(ApiError::HostBufferFull));
+        }
+        let value = CLValue::from_t(self.call_stack.clone()).map_err(Error::CLValue)?;
+        let value_size = value.inner_bytes().len() as u32;
+
+        if let Err(error) = self.write_host_buffer(value) {
+            return Ok(Err(error));
+        }
+
+        let output_size_bytes = value_size.to_le_bytes(); // Wasm is little-endian
+        if let Err(error) = self.memory.set(output_size, &output_size_bytes) {
+            return Err(Error::Interpreter(error.into()).into());
+        }
+        Ok(Ok(()))
+    }
+
     /// Writes runtime context's phase to [dest_ptr] in the Wasm memory.
     fn get_phase(&mut self, dest_ptr: u32) -> Result<(), Trap> {
         let phase = self.context.phase();
@@ -1616,6 +1767,14 @@ where
             .map_err(|e| Error::Interpreter(e.into()).into())
     }
 
+    /// Writes the seed of the current phase to [dest_ptr] in Wasm memory.
+    fn get_phase_seed(&mut self, dest_ptr: u32) -> Result<(), Trap> {
+        let seed = self.context.phase_seed()?;
+        self.memory
+            .set(dest_ptr, &seed)
+            .map_err(|e| Error::Interpreter(e.into()).into())
+    }
+
     /// Writes current blocktime to [dest_ptr] in Wasm memory.
     fn get_blocktime(&self, dest_ptr: u32) -> Result<(), Trap> {
         let blocktime = self
@@ -2004,6 +2163,9 @@ where
 
         let host_buffer = None;
 
+        let mut call_stack = self.call_stack.clone();
+        call_stack.push(CallStackElement::StoredContract(key));
+
         let context = RuntimeContext::new(
             self.context.state(),
             &mut named_keys,
@@ -2031,6 +2193,7 @@ where
             module,
             host_buffer,
             context,
+            call_stack,
         };
 
         let result = instance.invoke_export("call", &[], &mut runtime);
@@ -2325,11 +2488,104 @@ where
         Ok(Ok(()))
     }
 
+    /// Reads the value stored under the given key bytes in the dictionary identified by the given
+    /// `URef`, writing it to the host buffer.
+    fn dictionary_get(
+        &mut self,
+        uref_ptr: u32,
+        uref_size: u32,
+        key_ptr: u32,
+        key_size: u32,
+        output_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        if !self.can_write_to_host_buffer() {
+            // Exit early if the host buffer is already occupied
+            return Ok(Err(ApiError::HostBufferFull));
+        }
+
+        let uref = self.uref_from_mem(uref_ptr, uref_size)?;
+        let key_bytes = self.bytes_from_mem(key_ptr, key_size as usize)?;
+
+        let cl_value = match self.context.read_dictionary(&uref, &key_bytes)? {
+            Some(cl_value) => cl_value,
+            None => return Ok(Err(ApiError::ValueNotFound)),
+        };
+
+        let value_size = cl_value.inner_bytes().len() as u32;
+        if let Err(error) = self.write_host_buffer(cl_value) {
+            return Ok(Err(error));
+        }
+
+        let value_bytes = value_size.to_le_bytes(); // Wasm is little-endian
+        if let Err(error) = self.memory.set(output_size_ptr, &value_bytes) {
+            return Err(Error::Interpreter(error.into()).into());
+        }
+
+        Ok(Ok(()))
+    }
+
+    /// Writes `value` under the given key bytes in the dictionary identified by the given `URef`.
+    fn dictionary_put(
+        &mut self,
+        uref_ptr: u32,
+        uref_size: u32,
+        key_ptr: u32,
+        key_size: u32,
+        value_ptr: u32,
+        value_size: u32,
+    ) -> Result<(), Trap> {
+        let uref = self.uref_from_mem(uref_ptr, uref_size)?;
+        let key_bytes = self.bytes_from_mem(key_ptr, key_size as usize)?;
+        let cl_value = self.cl_value_from_mem(value_ptr, value_size)?;
+        self.context
+            .write_dictionary(&uref, &key_bytes, cl_value)
+            .map_err(Into::into)
+    }
+
     /// Reverts contract execution with a status specified.
     fn revert(&mut self, status: u32) -> Trap {
         Error::Revert(status.into()).into()
     }
 
+    /// Reverts contract execution with a status and a message specified.
+    fn revert_with_message(&mut self, status: u32, message_ptr: u32, message_size: u32) -> Trap {
+        match self.revert_message_from_mem(message_ptr, message_size) {
+            Ok(message) => Error::RevertWithMessage {
+                error: status.into(),
+                message,
+            }
+            .into(),
+            Err(error) => error.into(),
+        }
+    }
+
+    /// Reads a serialized `String` (defined as `message_ptr` and `message_size` tuple) from Wasm
+    /// memory, copying at most `REVERT_MESSAGE_MAX_LENGTH` bytes of its contents.
+    ///
+    /// The returned message records whether it was truncated.
+    fn revert_message_from_mem(
+        &self,
+        message_ptr: u32,
+        message_size: u32,
+    ) -> Result<RevertMessage, Error> {
+        if (message_size as usize) < U32_SERIALIZED_LENGTH {
+            return Err(Error::BytesRepr(bytesrepr::Error::EarlyEndOfStream));
+        }
+        let length_bytes = self.bytes_from_mem(message_ptr, U32_SERIALIZED_LENGTH)?;
+        let message_length: u32 = bytesrepr::deserialize(length_bytes)?;
+        if U32_SERIALIZED_LENGTH + message_length as usize != message_size as usize {
+            return Err(Error::BytesRepr(bytesrepr::Error::Formatting));
+        }
+
+        let truncated = message_length as usize > REVERT_MESSAGE_MAX_LENGTH;
+        let read_length = cmp::min(message_length as usize, REVERT_MESSAGE_MAX_LENGTH);
+        let message_bytes =
+            self.bytes_from_mem(message_ptr + U32_SERIALIZED_LENGTH as u32, read_length)?;
+        // Truncation may split a multi-byte character, so decode lossily.
+        let text = String::from_utf8_lossy(&message_bytes).into_owned();
+        Ok(RevertMessage { text, truncated })
+    }
+
     fn add_associated_key(
         &mut self,
         public_key_ptr: u32,
@@ -2828,10 +3084,38 @@ where
         Ok(Ok(()))
     }
 
+    /// Writes the BLAKE2b hash of the input bytes to the output buffer, charging
+    /// [`BLAKE2B_COST_PER_BYTE`] gas per input byte.
+    fn blake2b(
+        &mut self,
+        in_ptr: u32,
+        in_size: u32,
+        out_ptr: u32,
+        out_size: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        if out_size as usize != BLAKE2B_DIGEST_LENGTH {
+            return Ok(Err(ApiError::BufferTooSmall));
+        }
+
+        self.gas(Gas::new(
+            (u64::from(in_size) * BLAKE2B_COST_PER_BYTE).into(),
+        ))?;
+
+        let input = self.bytes_from_mem(in_ptr, in_size as usize)?;
+        let digest = Blake2bHash::new(&input);
+
+        if let Err(error) = self.memory.set(out_ptr, &digest.value()) {
+            return Err(Error::Interpreter(error.into()).into());
+        }
+
+        Ok(Ok(()))
+    }
+
     #[cfg(feature = "test-support")]
     fn print(&mut self, text_ptr: u32, text_size: u32) -> Result<(), Trap> {
         let text = self.string_from_mem(text_ptr, text_size)?;
         println!("{}", text);
+        PRINTED_LINES.with(|lines| lines.borrow_mut().push(text));
         Ok(())
     }
 }
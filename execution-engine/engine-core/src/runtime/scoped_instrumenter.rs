@@ -127,6 +127,16 @@ impl Drop for ScopedInstrumenter {
             FunctionIndex::GetMainPurseIndex => "host_function_get_main_purse",
             FunctionIndex::GetArgSizeFuncIndex => "host_function_get_arg_size",
             FunctionIndex::ReadHostBufferIndex => "host_function_read_host_buffer",
+            FunctionIndex::GetTotalArgsSizeFuncIndex => "host_function_get_total_args_size",
+            FunctionIndex::VerifySignatureFuncIndex => "host_function_verify_signature",
+            FunctionIndex::GetGasRemainingIndex => "host_function_get_gas_remaining",
+            FunctionIndex::GetArgCountFuncIndex => "host_function_get_arg_count",
+            FunctionIndex::RevertWithMessageFuncIndex => "host_function_revert_with_message",
+            FunctionIndex::GetCallStackFuncIndex => "host_function_get_call_stack",
+            FunctionIndex::Blake2bFuncIndex => "host_function_blake2b",
+            FunctionIndex::DictionaryGetFuncIndex => "host_function_dictionary_get",
+            FunctionIndex::DictionaryPutFuncIndex => "host_function_dictionary_put",
+            FunctionIndex::GetPhaseSeedIndex => "host_function_get_phase_seed",
             #[cfg(feature = "test-support")]
             FunctionIndex::PrintIndex => "host_function_print",
         };
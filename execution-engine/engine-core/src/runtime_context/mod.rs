@@ -50,6 +50,23 @@ pub(crate) fn attenuate_uref_for_account(account: &Account, uref: URef) -> URef
     }
 }
 
+/// The prefix hashed together with a dictionary's address to derive the seed of its entries.
+const DICTIONARY_SEED_PREFIX: &[u8] = b"dictionary";
+
+/// Returns the local-key seed under which the entries of the dictionary identified by `uref` are
+/// stored.
+///
+/// A contract stored under a URef uses that URef's address as the seed of its own local state, so
+/// the address is hashed with a prefix to keep dictionary entries out of that namespace.
+fn dictionary_seed(uref: &URef) -> [u8; KEY_LOCAL_SEED_LENGTH] {
+    let mut hasher = VarBlake2b::new(KEY_LOCAL_SEED_LENGTH).unwrap();
+    hasher.input(DICTIONARY_SEED_PREFIX);
+    hasher.input(&uref.addr());
+    let mut seed = [0; KEY_LOCAL_SEED_LENGTH];
+    hasher.variable_result(|hash| seed.clone_from_slice(hash));
+    seed
+}
+
 /// Holds information specific to the deployed contract.
 pub struct RuntimeContext<'a, R> {
     state: Rc<RefCell<TrackingCopy<R>>>,
@@ -276,6 +293,24 @@ where
         self.phase
     }
 
+    /// Returns a pseudo-random seed for the current phase of the deploy.
+    ///
+    /// The seed is a hash of the deploy hash, the block time and the phase, so replaying a deploy
+    /// reproduces it, while different deploys or phases of the same deploy get distinct seeds.
+    pub fn phase_seed(&self) -> Result<[u8; 32], Error> {
+        // 32 bytes for deploy hash + 8 bytes blocktime + 1 byte phase
+        let mut pre_hash_bytes = Vec::with_capacity(41);
+        pre_hash_bytes.extend_from_slice(&self.deploy_hash);
+        pre_hash_bytes.append(&mut self.blocktime.into_bytes()?);
+        pre_hash_bytes.append(&mut self.phase.into_bytes()?);
+
+        let mut hasher = VarBlake2b::new(32).unwrap();
+        hasher.input(&pre_hash_bytes);
+        let mut hash_bytes = [0; 32];
+        hasher.variable_result(|hash| hash_bytes.clone_from_slice(hash));
+        Ok(hash_bytes)
+    }
+
     /// Generates new function address.
     /// Function address is deterministic. It is a hash of public key, nonce and
     /// `fn_store_id`, which is a counter that is being incremented after
@@ -353,6 +388,49 @@ where
         Ok(())
     }
 
+    /// Reads the value stored under `key_bytes` in the dictionary identified by `uref`.
+    ///
+    /// Dictionary entries live in the local partition of global state, seeded by a hash of the
+    /// address of `uref`, so `uref` must be known to the current context and readable.
+    pub fn read_dictionary(
+        &mut self,
+        uref: &URef,
+        key_bytes: &[u8],
+    ) -> Result<Option<CLValue>, Error> {
+        self.validate_uref(uref)?;
+        self.validate_readable(&Key::URef(*uref))?;
+        let key = Key::local(dictionary_seed(uref), key_bytes);
+        let maybe_stored_value = self
+            .state
+            .borrow_mut()
+            .read(self.correlation_id, &key)
+            .map_err(Into::into)?;
+
+        if let Some(stored_value) = maybe_stored_value {
+            Ok(Some(stored_value.try_into().map_err(Error::TypeMismatch)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Writes `cl_value` under `key_bytes` in the dictionary identified by `uref`.
+    ///
+    /// `uref` must be known to the current context and writeable.
+    pub fn write_dictionary(
+        &mut self,
+        uref: &URef,
+        key_bytes: &[u8],
+        cl_value: CLValue,
+    ) -> Result<(), Error> {
+        self.validate_uref(uref)?;
+        self.validate_writeable(&Key::URef(*uref))?;
+        let value = StoredValue::CLValue(cl_value);
+        self.validate_value(&value)?;
+        let key = Key::local(dictionary_seed(uref), key_bytes);
+        self.state.borrow_mut().write(key, value);
+        Ok(())
+    }
+
     pub fn read_gs(&mut self, key: &Key) -> Result<Option<StoredValue>, Error> {
         self.validate_readable(key)?;
         self.validate_key(key)?;
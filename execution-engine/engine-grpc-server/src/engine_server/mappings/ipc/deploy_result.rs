@@ -32,6 +32,7 @@ impl From<(EngineStateError, ExecutionEffect, Gas)> for DeployResult {
             | error @ EngineStateError::InvalidPublicKeyLength { .. }
             | error @ EngineStateError::InvalidProtocolVersion { .. }
             | error @ EngineStateError::InvalidUpgradeConfig
+            | error @ EngineStateError::ArgsTooLarge { .. }
             | error @ EngineStateError::WasmPreprocessing(_)
             | error @ EngineStateError::WasmSerialization(_)
             | error @ EngineStateError::Exec(ExecutionError::DeploymentAuthorizationFailure)
@@ -64,6 +65,9 @@ impl From<(ExecutionError, ExecutionEffect, Gas)> for DeployResult {
             ExecutionError::Revert(status) => {
                 detail::execution_error(status.to_string(), effect, cost)
             }
+            error @ ExecutionError::RevertWithMessage { .. } => {
+                detail::execution_error(error.to_string(), effect, cost)
+            }
             ExecutionError::Interpreter(error) => detail::execution_error(error, effect, cost),
             // TODO(mateusz.gorski): Be more specific about execution errors
             other => detail::execution_error(format!("{:?}", other), effect, cost),
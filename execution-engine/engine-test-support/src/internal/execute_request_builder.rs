@@ -31,6 +31,7 @@ impl ExecuteRequestBuilder {
         self
     }
 
+    /// Sets the block time, in milliseconds, observed by the deploys via `runtime::get_blocktime`.
     pub fn with_block_time(mut self, block_time: u64) -> Self {
         self.execute_request.block_time = block_time;
         self
@@ -52,7 +53,17 @@ impl ExecuteRequestBuilder {
     ) -> Self {
         let mut rng = rand::thread_rng();
         let deploy_hash: [u8; 32] = rng.gen();
+        Self::standard_with_deploy_hash(public_key, session_file, session_args, deploy_hash)
+    }
 
+    /// Like [`ExecuteRequestBuilder::standard`], but with the given rather than a random deploy
+    /// hash, so that anything derived from the deploy hash is reproducible.
+    pub fn standard_with_deploy_hash(
+        public_key: PublicKey,
+        session_file: &str,
+        session_args: impl ArgsParser,
+        deploy_hash: [u8; 32],
+    ) -> Self {
         let deploy = DeployItemBuilder::new()
             .with_address(public_key)
             .with_session_code(session_file, session_args)
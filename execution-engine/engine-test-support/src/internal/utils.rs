@@ -5,18 +5,27 @@ use std::{
 };
 
 use lazy_static::lazy_static;
-
-use engine_core::engine_state::{
-    execution_result::ExecutionResult,
-    genesis::{ExecConfig, GenesisAccount, GenesisConfig},
-    run_genesis_request::RunGenesisRequest,
-    Error,
+use num_traits::identities::Zero;
+
+use contract::args_parser::ArgsParser;
+use engine_core::{
+    engine_state::{
+        execution_result::ExecutionResult,
+        genesis::{ExecConfig, GenesisAccount, GenesisConfig},
+        run_genesis_request::RunGenesisRequest,
+        Error,
+    },
+    execution,
 };
 use engine_shared::{
-    account::Account, additive_map::AdditiveMap, gas::Gas, stored_value::StoredValue,
-    transform::Transform,
+    account::Account, additive_map::AdditiveMap, gas::Gas, motes::Motes, newtypes::Blake2bHash,
+    stored_value::StoredValue, transform::Transform,
+};
+use types::{
+    account::PublicKey,
+    bytesrepr::{self, ToBytes},
+    ApiError, CLValue, Key, U512,
 };
-use types::Key;
 
 use crate::internal::{
     DEFAULT_CHAIN_NAME, DEFAULT_GENESIS_CONFIG_HASH, DEFAULT_GENESIS_TIMESTAMP,
@@ -118,6 +127,28 @@ pub fn create_exec_config(accounts: Vec<GenesisAccount>) -> ExecConfig {
     )
 }
 
+/// Returns a deterministic account address derived from `seed`.
+///
+/// Distinct seeds give distinct addresses, so tests needing many accounts can refer to them by
+/// index rather than by literal byte arrays.
+pub fn account_addr(seed: u64) -> [u8; 32] {
+    Blake2bHash::new(&seed.to_le_bytes()).value()
+}
+
+/// Returns `count` genesis accounts each funded with `balance`, with public keys given by
+/// [`account_addr`] for seeds `0..count`.
+pub fn create_funded_accounts(count: u64, balance: U512) -> Vec<GenesisAccount> {
+    (0..count)
+        .map(|seed| {
+            GenesisAccount::new(
+                PublicKey::ed25519_from(account_addr(seed)),
+                Motes::new(balance),
+                Motes::zero(),
+            )
+        })
+        .collect()
+}
+
 pub fn create_genesis_config(accounts: Vec<GenesisAccount>) -> GenesisConfig {
     let name = DEFAULT_CHAIN_NAME.to_string();
     let timestamp = DEFAULT_GENESIS_TIMESTAMP;
@@ -175,6 +206,39 @@ pub fn get_error_message<T: AsRef<ExecutionResult>, I: IntoIterator<Item = T>>(
     errors.join("\n")
 }
 
+/// Asserts that `execution_result` failed cleanly, i.e. by the contract explicitly reverting, and
+/// returns the [`ApiError`] it reverted with.
+///
+/// Panics if `execution_result` is a success, or if it failed in any other way, e.g. by the Wasm
+/// module trapping on a Rust panic or an out-of-bounds memory access, or by running out of gas.
+pub fn should_fail_cleanly(execution_result: &ExecutionResult) -> ApiError {
+    let error = match execution_result {
+        ExecutionResult::Failure { error, .. } => error,
+        ExecutionResult::Success { .. } => panic!("should be a failed execution result"),
+    };
+    match error {
+        Error::Exec(execution::Error::Revert(api_error))
+        | Error::Exec(execution::Error::RevertWithMessage {
+            error: api_error, ..
+        }) => *api_error,
+        other => panic!(
+            "should fail cleanly with a revert, but failed with {:?}",
+            other
+        ),
+    }
+}
+
+/// Serializes `args` in the same way as they are sent to the engine, then immediately
+/// deserializes them again.
+///
+/// This allows checking the encoding of a set of arguments without executing a contract, e.g. by
+/// calling [`CLValue::into_t`] on each of the returned values.
+pub fn round_trip_args(args: impl ArgsParser) -> Vec<CLValue> {
+    let cl_values = args.parse().expect("should parse args");
+    let bytes = cl_values.into_bytes().expect("should serialize args");
+    bytesrepr::deserialize(bytes).expect("should deserialize args")
+}
+
 #[allow(clippy::implicit_hasher)]
 pub fn get_account(transforms: &AdditiveMap<Key, Transform>, account: &Key) -> Option<Account> {
     transforms.get(account).and_then(|transform| {
@@ -14,7 +14,7 @@ use log::LevelFilter;
 
 use engine_core::{
     engine_state::{
-        execute_request::ExecuteRequest, execution_result::ExecutionResult,
+        self, execute_request::ExecuteRequest, execution_result::ExecutionResult,
         run_genesis_request::RunGenesisRequest, EngineConfig, EngineState, SYSTEM_ACCOUNT_ADDR,
     },
     execution,
@@ -51,7 +51,7 @@ use types::{
     CLValue, Key, URef, U512,
 };
 
-use crate::internal::utils;
+use crate::internal::{utils, DEFAULT_ACCOUNTS};
 
 /// LMDB initial map size is calculated based on DEFAULT_LMDB_PAGES and systems page size.
 ///
@@ -71,6 +71,9 @@ pub struct WasmTestBuilder<S> {
     engine_state: Rc<EngineState<S>>,
     /// [`ExecutionResult`] is wrapped in [`Rc`] to work around a missing [`Clone`] implementation
     exec_responses: Vec<Vec<Rc<ExecutionResult>>>,
+    /// Lines printed by contracts during each exec call, i.e. `logs[0]` is for first exec call
+    /// etc.
+    logs: Vec<Vec<String>>,
     upgrade_responses: Vec<UpgradeResponse>,
     genesis_hash: Option<Vec<u8>>,
     post_state_hash: Option<Vec<u8>>,
@@ -99,17 +102,24 @@ impl<S> WasmTestBuilder<S> {
 
 impl Default for InMemoryWasmTestBuilder {
     fn default() -> Self {
-        Self::initialize_logging();
         let engine_config = EngineConfig::new()
             .with_use_system_contracts(cfg!(feature = "use-system-contracts"))
             .with_enable_bonding(cfg!(feature = "enable-bonding"));
+        Self::with_engine_config(engine_config)
+    }
+}
 
+impl InMemoryWasmTestBuilder {
+    /// Creates a builder over empty global state, using the supplied engine configuration.
+    pub fn with_engine_config(engine_config: EngineConfig) -> Self {
+        Self::initialize_logging();
         let global_state = InMemoryGlobalState::empty().expect("should create global state");
         let engine_state = EngineState::new(global_state, engine_config);
 
         WasmTestBuilder {
             engine_state: Rc::new(engine_state),
             exec_responses: Vec::new(),
+            logs: Vec::new(),
             upgrade_responses: Vec::new(),
             genesis_hash: None,
             post_state_hash: None,
@@ -131,6 +141,7 @@ impl<S> Clone for WasmTestBuilder<S> {
         WasmTestBuilder {
             engine_state: Rc::clone(&self.engine_state),
             exec_responses: self.exec_responses.clone(),
+            logs: self.logs.clone(),
             upgrade_responses: self.upgrade_responses.clone(),
             genesis_hash: self.genesis_hash.clone(),
             post_state_hash: self.post_state_hash.clone(),
@@ -199,6 +210,7 @@ impl LmdbWasmTestBuilder {
         WasmTestBuilder {
             engine_state: Rc::new(engine_state),
             exec_responses: Vec::new(),
+            logs: Vec::new(),
             upgrade_responses: Vec::new(),
             genesis_hash: None,
             post_state_hash: None,
@@ -260,6 +272,7 @@ impl LmdbWasmTestBuilder {
         WasmTestBuilder {
             engine_state: Rc::new(engine_state),
             exec_responses: Vec::new(),
+            logs: Vec::new(),
             upgrade_responses: Vec::new(),
             genesis_hash: None,
             post_state_hash: Some(post_state_hash),
@@ -296,6 +309,7 @@ where
         WasmTestBuilder {
             engine_state: result.0.engine_state,
             exec_responses: Vec::new(),
+            logs: Vec::new(),
             upgrade_responses: Vec::new(),
             genesis_hash: result.0.genesis_hash,
             post_state_hash: result.0.post_state_hash,
@@ -355,6 +369,17 @@ where
         self
     }
 
+    /// Runs genesis with the default accounts plus `count` accounts each funded with `balance`.
+    ///
+    /// The extra accounts' public keys are `PublicKey::ed25519_from(utils::account_addr(seed))` for
+    /// seeds `0..count`.
+    pub fn run_genesis_with_funded_accounts(&mut self, count: u64, balance: U512) -> &mut Self {
+        let mut accounts = DEFAULT_ACCOUNTS.clone();
+        accounts.extend(utils::create_funded_accounts(count, balance));
+        let run_genesis_request = utils::create_run_genesis_request(accounts);
+        self.run_genesis(&run_genesis_request)
+    }
+
     pub fn query(
         &self,
         maybe_post_state: Option<Vec<u8>>,
@@ -406,6 +431,25 @@ where
         );
         self.exec_responses
             .push(exec_response.unwrap().into_iter().map(Rc::new).collect());
+        #[cfg(feature = "test-support")]
+        let logs = engine_core::runtime::take_printed_lines();
+        #[cfg(not(feature = "test-support"))]
+        let logs = Vec::new();
+        self.logs.push(logs);
+        self
+    }
+
+    /// Executes and commits each of the given requests in turn.
+    ///
+    /// Every request is executed and then committed on its own, as if by calling
+    /// [`exec`](WasmTestBuilder::exec) followed by [`commit`](WasmTestBuilder::commit), so each one
+    /// produces a new post-state and later requests observe the writes of earlier ones.  The
+    /// response of each request is available by index via
+    /// [`get_exec_response`](WasmTestBuilder::get_exec_response).
+    pub fn exec_commit_sequentially(&mut self, exec_requests: Vec<ExecuteRequest>) -> &mut Self {
+        for exec_request in exec_requests {
+            self.exec(exec_request).commit();
+        }
         self
     }
 
@@ -519,11 +563,43 @@ where
         exec_result.is_failure()
     }
 
+    /// Returns `true` if the deploy at `deploy_index` failed by running out of gas, as opposed to
+    /// reverting or trapping.
+    ///
+    /// Deploys are indexed in the order they were executed, across all calls to
+    /// [`exec`](WasmTestBuilder::exec).
+    pub fn is_out_of_gas(&self, deploy_index: usize) -> bool {
+        let exec_result = self
+            .exec_responses
+            .iter()
+            .flatten()
+            .nth(deploy_index)
+            .expect("Unable to get execution result");
+        match exec_result.as_error() {
+            Some(engine_state::Error::Exec(execution::Error::GasLimit)) => true,
+            _ => false,
+        }
+    }
+
     /// Gets the transform map that's cached between runs
     pub fn get_transforms(&self) -> Vec<AdditiveMap<Key, Transform>> {
         self.transforms.clone()
     }
 
+    /// Returns the transforms produced by the deploy at `deploy_index`, sorted by key.
+    ///
+    /// Deploys are indexed in the order they were executed, across all calls to
+    /// [`exec`](WasmTestBuilder::exec).  Returns `None` if no such deploy has been executed.
+    pub fn get_deploy_transforms(&self, deploy_index: usize) -> Option<Vec<(Key, Transform)>> {
+        let transforms = self.transforms.get(deploy_index)?;
+        let mut result: Vec<(Key, Transform)> = transforms
+            .iter()
+            .map(|(key, transform)| (*key, transform.clone()))
+            .collect();
+        result.sort_by_key(|(key, _)| *key);
+        Some(result)
+    }
+
     pub fn get_bonded_validators(&self) -> Vec<HashMap<PublicKey, U512>> {
         self.bonded_validators.clone()
     }
@@ -569,6 +645,18 @@ where
             .expect("Should have post-state hash.")
     }
 
+    /// Returns a copy of this builder whose subsequent execs run against `post_state_hash`.
+    ///
+    /// `post_state_hash` should be a committed state of this builder's engine state, e.g. one
+    /// previously returned by [`get_post_state_hash`](WasmTestBuilder::get_post_state_hash), which
+    /// allows several divergent deploys to be run from the same snapshot.  The engine state itself
+    /// is shared, so the original builder is unaffected by execs on the copy.
+    pub fn from_state_hash(&self, post_state_hash: Vec<u8>) -> Self {
+        let mut builder = self.clone();
+        builder.post_state_hash = Some(post_state_hash);
+        builder
+    }
+
     pub fn get_engine_state(&self) -> &EngineState<S> {
         &self.engine_state
     }
@@ -581,6 +669,14 @@ where
         self.exec_responses.get(index)
     }
 
+    /// Returns the lines printed via `runtime::print` during the exec call at `index`.
+    ///
+    /// Printing is only available when the `test-support` feature is enabled; otherwise this is
+    /// always empty.
+    pub fn get_logs(&self, index: usize) -> Vec<String> {
+        self.logs.get(index).cloned().unwrap_or_default()
+    }
+
     pub fn get_exec_responses_count(&self) -> usize {
         self.exec_responses.len()
     }
@@ -645,6 +741,10 @@ where
         }
     }
 
+    /// Returns the gas cost of each deploy of the `index`-th exec call.
+    ///
+    /// The cost of a deploy covers both its payment and session code.  Failed deploys are included
+    /// too, reporting the gas consumed up to the point of failure.
     pub fn exec_costs(&self, index: usize) -> Vec<Gas> {
         let exec_response = self
             .get_exec_response(index)
@@ -2,20 +2,22 @@ use lazy_static::lazy_static;
 
 use engine_test_support::{
     internal::{
-        ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
         DEFAULT_RUN_GENESIS_REQUEST,
     },
     DEFAULT_ACCOUNT_ADDR,
 };
 use types::{
     account::{PublicKey, Weight},
-    U512,
+    ApiError, U512,
 };
 
 const CONTRACT_ADD_UPDATE_ASSOCIATED_KEY: &str = "add_update_associated_key.wasm";
 const CONTRACT_REMOVE_ASSOCIATED_KEY: &str = "remove_associated_key.wasm";
 const CONTRACT_TRANSFER_PURSE_TO_ACCOUNT: &str = "transfer_purse_to_account.wasm";
+const CONTRACT_UPDATE_ASSOCIATED_KEY_WEIGHT: &str = "update_associated_key_weight.wasm";
 const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
+const ASSOCIATED_KEY: PublicKey = PublicKey::ed25519_from([42; 32]);
 
 lazy_static! {
     static ref ACCOUNT_1_INITIAL_FUND: U512 = *DEFAULT_PAYMENT * 10;
@@ -82,3 +84,67 @@ fn should_manage_associated_key() {
     let is_error = builder.is_error();
     assert!(!is_error);
 }
+
+#[ignore]
+#[test]
+fn should_raise_associated_key_weight() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_UPDATE_ASSOCIATED_KEY_WEIGHT,
+        (String::from("raise"),),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let weight = account
+        .get_associated_key_weight(ASSOCIATED_KEY)
+        .expect("weight");
+    assert_eq!(*weight, Weight::new(3), "unexpected weight");
+}
+
+#[ignore]
+#[test]
+fn should_not_lower_associated_key_weight_below_threshold() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_UPDATE_ASSOCIATED_KEY_WEIGHT,
+        (String::from("lower-below-threshold"),),
+    )
+    .build();
+
+    let result = InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit()
+        .finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response");
+    let error_message = utils::get_error_message(response);
+    assert!(
+        error_message.contains(&format!("{:?}", ApiError::ThresholdViolation)),
+        error_message
+    );
+
+    let account = result
+        .builder()
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    assert_eq!(
+        account.get_associated_key_weight(ASSOCIATED_KEY),
+        None,
+        "reverted deploy should not add the key"
+    );
+}
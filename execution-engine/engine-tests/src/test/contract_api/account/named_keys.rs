@@ -20,6 +20,8 @@ const COMMAND_TEST_READ_UREF1: &str = "test-read-uref1";
 const COMMAND_TEST_READ_UREF2: &str = "test-read-uref2";
 const COMMAND_INCREASE_UREF2: &str = "increase-uref2";
 const COMMAND_OVERWRITE_UREF2: &str = "overwrite-uref2";
+const COMMAND_TEST_HAS_UREF1: &str = "test-has-uref1";
+const COMMAND_TEST_HAS_NO_UREF1: &str = "test-has-no-uref1";
 
 fn run_command(builder: &mut InMemoryWasmTestBuilder, command: &str) {
     let exec_request =
@@ -104,3 +106,35 @@ fn should_run_named_keys_contract() {
     assert!(!account.named_keys().contains_key(KEY1));
     assert!(!account.named_keys().contains_key(KEY2));
 }
+
+#[ignore]
+#[test]
+fn should_check_for_and_remove_named_key() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    run_command(&mut builder, COMMAND_TEST_HAS_NO_UREF1);
+
+    run_command(&mut builder, COMMAND_CREATE_UREF1);
+    run_command(&mut builder, COMMAND_TEST_HAS_UREF1);
+    let value: String = CLValue::try_from(
+        builder
+            .query(None, Key::Account(DEFAULT_ACCOUNT_ADDR), &[KEY1])
+            .expect("should have value"),
+    )
+    .expect("should have CLValue")
+    .into_t()
+    .expect("should convert successfully");
+    assert_eq!(value, "Hello, world!");
+
+    run_command(&mut builder, COMMAND_REMOVE_UREF1);
+    run_command(&mut builder, COMMAND_TEST_HAS_NO_UREF1);
+    assert!(builder
+        .query(None, Key::Account(DEFAULT_ACCOUNT_ADDR), &[KEY1])
+        .is_err());
+
+    // Removing an absent key is a no-op rather than an error.
+    run_command(&mut builder, COMMAND_REMOVE_UREF1);
+    run_command(&mut builder, COMMAND_TEST_HAS_NO_UREF1);
+}
@@ -0,0 +1,60 @@
+use std::convert::TryFrom;
+
+use engine_core::runtime::BLAKE2B_COST_PER_BYTE;
+use engine_shared::{gas::Gas, newtypes::Blake2bHash};
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{CLValue, Key, U512};
+
+const CONTRACT_BLAKE2B: &str = "blake2b.wasm";
+const DIGEST_NAME: &str = "digest";
+/// BLAKE2b with a 32-byte digest of the empty input.
+const EMPTY_INPUT_DIGEST: &str = "0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8";
+
+/// Runs the blake2b contract on `input` and returns the stored digest and the deploy cost.
+fn hash_in_contract(input: Vec<u8>) -> ([u8; 32], Gas) {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_BLAKE2B, (input,)).build();
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let stored_value = builder
+        .query(None, Key::Account(DEFAULT_ACCOUNT_ADDR), &[DIGEST_NAME])
+        .expect("should have digest");
+    let digest = CLValue::try_from(stored_value)
+        .expect("should be a CLValue")
+        .into_t()
+        .expect("should be a 32-byte array");
+    (digest, builder.exec_costs(0)[0])
+}
+
+#[ignore]
+#[test]
+fn should_hash_empty_input() {
+    let (digest, _cost) = hash_in_contract(Vec::new());
+    assert_eq!(base16::encode_lower(&digest), EMPTY_INPUT_DIGEST);
+}
+
+#[ignore]
+#[test]
+fn should_match_host_blake2b() {
+    let input = b"Hello, world!".to_vec();
+    let (digest, _cost) = hash_in_contract(input.clone());
+    assert_eq!(digest, Blake2bHash::new(&input).value());
+}
+
+#[ignore]
+#[test]
+fn should_charge_gas_proportional_to_input_size() {
+    const INPUT_SIZE: usize = 10_000;
+    let (_digest, small_cost) = hash_in_contract(Vec::new());
+    let (_digest, large_cost) = hash_in_contract(vec![7u8; INPUT_SIZE]);
+    let hashing_cost = Gas::new(U512::from(INPUT_SIZE as u64 * BLAKE2B_COST_PER_BYTE));
+    assert!(large_cost >= small_cost + hashing_cost);
+}
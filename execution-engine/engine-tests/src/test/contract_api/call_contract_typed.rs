@@ -0,0 +1,50 @@
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{ApiError, U512};
+
+const CONTRACT_CALL_CONTRACT_TYPED: &str = "call_contract_typed.wasm";
+
+#[repr(u16)]
+enum CallContractTypedError {
+    ZeroAmount = 0,
+}
+
+fn run_call_contract_typed(amount: U512) -> InMemoryWasmTestBuilder {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_CALL_CONTRACT_TYPED,
+        (amount,),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit();
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_pass_u512_to_stored_contract_and_read_u512_result() {
+    run_call_contract_typed(U512::from(21)).expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_propagate_revert_from_stored_contract() {
+    let builder = run_call_contract_typed(U512::zero());
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("there should be a response");
+    assert_eq!(
+        utils::should_fail_cleanly(&response[0]),
+        ApiError::User(CallContractTypedError::ZeroAmount as u16)
+    );
+}
@@ -0,0 +1,59 @@
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::ApiError;
+
+const CONTRACT_CONTRACT_ERROR: &str = "contract_error.wasm";
+const REVERT_VIA_RUNTIME: u8 = 0;
+const REVERT_VIA_METHOD: u8 = 1;
+const REVERT_VIA_UNWRAP: u8 = 2;
+
+/// Runs contract_error contract, which reverts with an error declared via `contract_error!` in the
+/// way selected by `method`, and returns the error it reverted with
+fn revert_with_contract_error(method: u8) -> ApiError {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_CONTRACT_ERROR, (method,))
+            .build();
+    let result = InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit()
+        .finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response");
+
+    utils::should_fail_cleanly(&response[0])
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_explicit_discriminant() {
+    assert_eq!(
+        revert_with_contract_error(REVERT_VIA_RUNTIME),
+        ApiError::User(10)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_implicit_discriminant_via_revert_method() {
+    assert_eq!(
+        revert_with_contract_error(REVERT_VIA_METHOD),
+        ApiError::User(11)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_largest_discriminant_via_unwrap_or_revert() {
+    assert_eq!(
+        revert_with_contract_error(REVERT_VIA_UNWRAP),
+        ApiError::User(u16::max_value())
+    );
+}
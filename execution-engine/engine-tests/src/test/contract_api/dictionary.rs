@@ -0,0 +1,60 @@
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+
+const CONTRACT_DICTIONARY: &str = "dictionary.wasm";
+const CONTRACT_DICTIONARY_READ_MINT_LOCAL_STATE: &str = "dictionary_read_mint_local_state.wasm";
+const DICTIONARY_NAME: &str = "dictionary";
+const COMMAND_PUT: &str = "put";
+const COMMAND_GET: &str = "get";
+
+#[ignore]
+#[test]
+fn should_put_and_get_dictionary_entries_without_growing_named_keys() {
+    let put_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_DICTIONARY, (COMMAND_PUT,))
+            .build();
+    let get_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_DICTIONARY, (COMMAND_GET,))
+            .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(put_request)
+        .expect_success()
+        .commit()
+        .exec(get_request)
+        .expect_success()
+        .commit();
+
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    let named_keys = account.named_keys();
+    assert!(named_keys.contains_key(DICTIONARY_NAME));
+    assert!(
+        !named_keys.keys().any(|name| name.starts_with("entry-")),
+        "dictionary entries should not be stored as named keys"
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_read_mint_local_state_via_dictionary() {
+    // The mint is stored under a URef, so its local state would be readable through that URef if
+    // dictionary entries shared the contract's local namespace.
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_DICTIONARY_READ_MINT_LOCAL_STATE,
+        (),
+    )
+    .build();
+
+    InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+}
@@ -1,7 +1,10 @@
 use contract::args_parser::ArgsParser;
+use engine_core::engine_state::CONV_RATE;
+use engine_shared::{gas::Gas, motes::Motes};
 use engine_test_support::{
     internal::{
-        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
+        DEFAULT_RUN_GENESIS_REQUEST,
     },
     DEFAULT_ACCOUNT_ADDR,
 };
@@ -45,6 +48,30 @@ fn call_get_arg(args: impl ArgsParser) -> Result<(), String> {
     Err(error_message)
 }
 
+#[test]
+fn should_round_trip_string_and_u512_args() {
+    let cl_values = utils::round_trip_args((String::from(ARG0_VALUE), U512::from(ARG1_VALUE)));
+    assert_eq!(cl_values.len(), 2);
+
+    let arg0: String = cl_values[0].clone().into_t().expect("should be a String");
+    assert_eq!(arg0, ARG0_VALUE);
+    let arg1: U512 = cl_values[1].clone().into_t().expect("should be a U512");
+    assert_eq!(arg1, U512::from(ARG1_VALUE));
+}
+
+#[test]
+fn should_round_trip_nested_vec_arg() {
+    let matrix: Vec<Vec<U512>> = vec![vec![U512::from(ARG1_VALUE)], vec![], vec![U512::one(); 3]];
+    let cl_values = utils::round_trip_args((matrix.clone(),));
+    assert_eq!(cl_values.len(), 1);
+
+    let arg0: Vec<Vec<U512>> = cl_values[0]
+        .clone()
+        .into_t()
+        .expect("should be a nested Vec");
+    assert_eq!(arg0, matrix);
+}
+
 #[ignore]
 #[test]
 fn should_use_passed_argument() {
@@ -88,3 +115,34 @@ fn should_revert_with_invalid_argument() {
         ApiError::User(GetArgContractError::InvalidArgument1 as u16),
     )));
 }
+
+/// Runs get_arg contract and returns the gas cost of the deploy
+fn get_arg_cost(args: impl ArgsParser) -> Gas {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_GET_ARG, args).build();
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit();
+
+    let costs = builder.exec_costs(0);
+    assert_eq!(costs.len(), 1);
+    costs[0]
+}
+
+#[ignore]
+#[test]
+fn should_charge_gas_below_payment() {
+    let max_cost =
+        Gas::from_motes(Motes::new(*DEFAULT_PAYMENT), CONV_RATE).expect("should convert");
+
+    let cost = get_arg_cost((String::from(ARG0_VALUE), U512::from(ARG1_VALUE)));
+    assert!(cost > Gas::default(), "cost should be nonzero");
+    assert!(cost < max_cost, "cost should be below payment");
+
+    // Gas is still consumed by a deploy which reverts.
+    let reverted_cost = get_arg_cost(());
+    assert!(reverted_cost > Gas::default(), "cost should be nonzero");
+    assert!(reverted_cost < max_cost, "cost should be below payment");
+}
@@ -0,0 +1,102 @@
+use contract::args_parser::ArgsParser;
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{ApiError, U512};
+
+#[repr(u16)]
+enum GetArgCheckedContractError {
+    Missing = 0,
+    Deserialization,
+    UnexpectedValue,
+}
+
+const CONTRACT_GET_ARG_CHECKED: &str = "get_arg_checked.wasm";
+const EXPECTED_VALUE: u64 = 42;
+const MATCH_ON_ERROR: u8 = 0;
+const UNWRAP_OR_REVERT: u8 = 1;
+
+/// Calls get_arg_checked contract and returns Ok(()) in case no error, or the error it reverted
+/// with
+fn call_get_arg_checked(args: impl ArgsParser) -> Result<(), ApiError> {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_GET_ARG_CHECKED, args)
+            .build();
+    let result = InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit()
+        .finish();
+
+    if !result.builder().is_error() {
+        return Ok(());
+    }
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response");
+
+    Err(utils::should_fail_cleanly(&response[0]))
+}
+
+#[ignore]
+#[test]
+fn should_get_arg_checked() {
+    call_get_arg_checked((MATCH_ON_ERROR, U512::from(EXPECTED_VALUE)))
+        .expect("should read the argument");
+    call_get_arg_checked((UNWRAP_OR_REVERT, U512::from(EXPECTED_VALUE)))
+        .expect("should read the argument");
+}
+
+#[ignore]
+#[test]
+fn should_get_arg_checked_with_unexpected_value() {
+    assert_eq!(
+        call_get_arg_checked((MATCH_ON_ERROR, U512::from(EXPECTED_VALUE + 1))),
+        Err(ApiError::User(
+            GetArgCheckedContractError::UnexpectedValue as u16
+        ))
+    );
+}
+
+#[ignore]
+#[test]
+fn should_match_on_missing_arg() {
+    assert_eq!(
+        call_get_arg_checked((MATCH_ON_ERROR,)),
+        Err(ApiError::User(GetArgCheckedContractError::Missing as u16))
+    );
+}
+
+#[ignore]
+#[test]
+fn should_match_on_invalid_arg() {
+    assert_eq!(
+        call_get_arg_checked((MATCH_ON_ERROR, String::from("Hello, world!"))),
+        Err(ApiError::User(
+            GetArgCheckedContractError::Deserialization as u16
+        ))
+    );
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_missing_argument() {
+    assert_eq!(
+        call_get_arg_checked((UNWRAP_OR_REVERT,)),
+        Err(ApiError::MissingArgument)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_invalid_argument() {
+    assert_eq!(
+        call_get_arg_checked((UNWRAP_OR_REVERT, String::from("Hello, world!"))),
+        Err(ApiError::InvalidArgument)
+    );
+}
@@ -0,0 +1,55 @@
+use std::convert::TryFrom;
+
+use contract::args_parser::ArgsParser;
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{CLValue, Key, U512};
+
+const CONTRACT_GET_ARG_COUNT: &str = "get_arg_count.wasm";
+const ARG_COUNT_KEY: &str = "arg_count";
+
+fn get_arg_count(args: impl ArgsParser) -> u32 {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_GET_ARG_COUNT, args).build();
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let stored_value = builder
+        .query(None, Key::Account(DEFAULT_ACCOUNT_ADDR), &[ARG_COUNT_KEY])
+        .expect("should have arg count");
+    CLValue::try_from(stored_value)
+        .expect("should be CLValue")
+        .into_t()
+        .expect("should be u32")
+}
+
+#[ignore]
+#[test]
+fn should_report_zero_args() {
+    assert_eq!(get_arg_count(()), 0);
+}
+
+#[ignore]
+#[test]
+fn should_report_one_arg() {
+    assert_eq!(get_arg_count((U512::from(42),)), 1);
+}
+
+#[ignore]
+#[test]
+fn should_report_five_args() {
+    let args = (
+        1u8,
+        2u32,
+        String::from("Hello, world!"),
+        U512::from(42),
+        DEFAULT_ACCOUNT_ADDR,
+    );
+    assert_eq!(get_arg_count(args), 5);
+}
@@ -0,0 +1,66 @@
+use engine_core::engine_state::EngineConfig;
+use engine_shared::gas::Gas;
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::U512;
+
+const CONTRACT_GET_ARG_BYTES: &str = "get_arg_bytes.wasm";
+const COST_PER_BYTE: u64 = 1_000;
+const SMALL_PAYLOAD_LENGTH: usize = 10;
+const LARGE_PAYLOAD_LENGTH: usize = 10_000;
+
+/// Runs get_arg_bytes contract with a payload of the given length and returns the gas cost of the
+/// deploy
+fn get_arg_bytes_cost(get_arg_cost_per_byte: u64, payload_length: usize) -> Gas {
+    let engine_config = EngineConfig::new().with_get_arg_cost_per_byte(get_arg_cost_per_byte);
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_GET_ARG_BYTES,
+        (vec![1u8; payload_length],),
+    )
+    .build();
+    let mut builder = InMemoryWasmTestBuilder::with_engine_config(engine_config);
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let costs = builder.exec_costs(0);
+    assert_eq!(costs.len(), 1);
+    costs[0]
+}
+
+/// Returns the gas charged for copying arguments, i.e. the cost with `COST_PER_BYTE` less the cost
+/// of the same deploy with argument copies made free
+fn get_arg_charge(payload_length: usize) -> Gas {
+    get_arg_bytes_cost(COST_PER_BYTE, payload_length) - get_arg_bytes_cost(0, payload_length)
+}
+
+#[ignore]
+#[test]
+fn should_charge_get_arg_gas_proportional_to_argument_size() {
+    let small_charge = get_arg_charge(SMALL_PAYLOAD_LENGTH);
+    let large_charge = get_arg_charge(LARGE_PAYLOAD_LENGTH);
+    assert!(small_charge > Gas::default(), "get_arg should not be free");
+
+    // Both deploys copy the same payment argument, so the difference in charges is exactly the
+    // per-byte cost of the extra session argument bytes.
+    let extra_bytes = (LARGE_PAYLOAD_LENGTH - SMALL_PAYLOAD_LENGTH) as u64;
+    assert_eq!(
+        large_charge - small_charge,
+        Gas::new(U512::from(extra_bytes * COST_PER_BYTE))
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_charge_get_arg_gas_by_default() {
+    let default_cost = get_arg_bytes_cost(
+        EngineConfig::default().get_arg_cost_per_byte(),
+        LARGE_PAYLOAD_LENGTH,
+    );
+    assert_eq!(default_cost, get_arg_bytes_cost(0, LARGE_PAYLOAD_LENGTH));
+}
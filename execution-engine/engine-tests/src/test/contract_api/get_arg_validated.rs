@@ -0,0 +1,74 @@
+use contract::args_parser::ArgsParser;
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{ApiError, U512};
+
+#[derive(Debug)]
+#[repr(u16)]
+enum GetArgValidatedContractError {
+    ZeroAmount = 0,
+}
+
+const CONTRACT_GET_ARG_VALIDATED: &str = "get_arg_validated.wasm";
+const AMOUNT: u64 = 42;
+
+/// Calls get_arg_validated contract and returns Ok(()) in case no error, or String which is the
+/// error message returned by the engine
+fn call_get_arg_validated(args: impl ArgsParser) -> Result<(), String> {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_GET_ARG_VALIDATED, args)
+            .build();
+    let result = InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit()
+        .finish();
+
+    if !result.builder().is_error() {
+        return Ok(());
+    }
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response");
+
+    Err(utils::get_error_message(response))
+}
+
+#[ignore]
+#[test]
+fn should_accept_valid_arg() {
+    call_get_arg_validated((U512::from(AMOUNT),)).expect("should accept nonzero amount");
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_validation_error() {
+    assert!(call_get_arg_validated((U512::zero(),))
+        .expect_err("should fail")
+        .contains(&format!(
+            "{:?}",
+            ApiError::User(GetArgValidatedContractError::ZeroAmount as u16),
+        )));
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_invalid_argument() {
+    assert!(call_get_arg_validated((String::from("Hello, world!"),))
+        .expect_err("should fail")
+        .contains(&format!("{:?}", ApiError::InvalidArgument)));
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_missing_arg() {
+    assert!(call_get_arg_validated(())
+        .expect_err("should fail")
+        .contains(&format!("{:?}", ApiError::MissingArgument)));
+}
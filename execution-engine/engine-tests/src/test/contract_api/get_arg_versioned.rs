@@ -0,0 +1,79 @@
+use contract::args_parser::ArgsParser;
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{ApiError, U512};
+
+#[derive(Debug)]
+#[repr(u16)]
+enum GetArgVersionedContractError {
+    MissingArgument = 0,
+    InvalidArgument,
+}
+
+const CONTRACT_GET_ARG_VERSIONED: &str = "get_arg_versioned.wasm";
+const VERSION_1: u8 = 1;
+const VERSION_2: u8 = 2;
+const UNSUPPORTED_VERSION: u8 = 3;
+const AMOUNT: u64 = 42;
+const MEMO: &str = "Hello, world!";
+
+/// Calls get_arg_versioned contract and returns Ok(()) in case no error, or String which is the
+/// error message returned by the engine
+fn call_get_arg_versioned(args: impl ArgsParser) -> Result<(), String> {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_GET_ARG_VERSIONED, args)
+            .build();
+    let result = InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit()
+        .finish();
+
+    if !result.builder().is_error() {
+        return Ok(());
+    }
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response");
+
+    Err(utils::get_error_message(response))
+}
+
+#[ignore]
+#[test]
+fn should_decode_v1_and_v2_arguments() {
+    call_get_arg_versioned(((VERSION_1, U512::from(AMOUNT)),))
+        .expect("should decode a v1 argument");
+    call_get_arg_versioned(((VERSION_2, (U512::from(AMOUNT), String::from(MEMO))),))
+        .expect("should decode a v2 argument");
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_unsupported_version() {
+    assert!(
+        call_get_arg_versioned(((UNSUPPORTED_VERSION, U512::from(AMOUNT)),))
+            .expect_err("should fail")
+            .contains(&format!(
+                "{:?}",
+                ApiError::User(GetArgVersionedContractError::InvalidArgument as u16),
+            ))
+    );
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_missing_versioned_arg() {
+    assert!(call_get_arg_versioned(())
+        .expect_err("should fail")
+        .contains(&format!(
+            "{:?}",
+            ApiError::User(GetArgVersionedContractError::MissingArgument as u16),
+        )));
+}
@@ -22,3 +22,29 @@ fn should_run_get_blocktime_contract() {
         .commit()
         .expect_success();
 }
+
+#[ignore]
+#[test]
+fn should_observe_block_time_of_each_deploy() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    for block_time in &[1_000u64, 86_400_000] {
+        let exec_request = ExecuteRequestBuilder::standard(
+            DEFAULT_ACCOUNT_ADDR,
+            CONTRACT_GET_BLOCKTIME,
+            (*block_time,),
+        )
+        .with_block_time(*block_time)
+        .build();
+        builder.exec(exec_request).expect_success().commit();
+    }
+
+    // The contract traps if the block time it observes differs from its argument.
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_GET_BLOCKTIME, (1_000u64,))
+            .with_block_time(2_000)
+            .build();
+    builder.exec(exec_request).commit();
+    assert!(builder.is_error());
+}
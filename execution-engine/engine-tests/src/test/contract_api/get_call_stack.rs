@@ -0,0 +1,68 @@
+use engine_test_support::{
+    internal::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
+        DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::account::PublicKey;
+
+const CONTRACT_GET_CALL_STACK: &str = "get_call_stack.wasm";
+const CONTRACT_TRANSFER_PURSE_TO_ACCOUNT: &str = "transfer_purse_to_account.wasm";
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
+
+#[ignore]
+#[test]
+fn should_get_call_stack_and_immediate_caller_across_subcalls() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_GET_CALL_STACK,
+        (DEFAULT_ACCOUNT_ADDR,),
+    )
+    .build();
+    InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit()
+        .expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_get_call_stack_of_other_account() {
+    let exec_request_1 = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_PURSE_TO_ACCOUNT,
+        (ACCOUNT_1_ADDR, *DEFAULT_PAYMENT),
+    )
+    .build();
+    let exec_request_2 =
+        ExecuteRequestBuilder::standard(ACCOUNT_1_ADDR, CONTRACT_GET_CALL_STACK, (ACCOUNT_1_ADDR,))
+            .build();
+    InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request_1)
+        .commit()
+        .expect_success()
+        .exec(exec_request_2)
+        .commit()
+        .expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_fail_with_call_stack_of_unexpected_account() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_GET_CALL_STACK,
+        (ACCOUNT_1_ADDR,),
+    )
+    .build();
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit();
+
+    assert!(builder.is_error());
+}
@@ -0,0 +1,57 @@
+use std::convert::TryFrom;
+
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, bytesrepr::FromBytes, CLTyped, CLValue, Key, U512};
+
+const CONTRACT_GET_EIGHT_ARGS: &str = "get_eight_args.wasm";
+
+fn query_arg<T: CLTyped + FromBytes>(builder: &InMemoryWasmTestBuilder, i: usize) -> T {
+    let name = format!("arg{}", i);
+    let stored_value = builder
+        .query(None, Key::Account(DEFAULT_ACCOUNT_ADDR), &[&name])
+        .expect("should have stored arg");
+    CLValue::try_from(stored_value)
+        .expect("should be CLValue")
+        .into_t()
+        .expect("should have expected type")
+}
+
+#[ignore]
+#[test]
+fn should_read_back_every_element_of_eight_tuple() {
+    let args = (
+        String::from("Hello, world!"),
+        U512::from(42),
+        u8::max_value(),
+        u32::max_value(),
+        u64::max_value(),
+        i32::min_value(),
+        PublicKey::ed25519_from([42; 32]),
+        true,
+    );
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_GET_EIGHT_ARGS,
+        args.clone(),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(query_arg::<String>(&builder, 0), args.0);
+    assert_eq!(query_arg::<U512>(&builder, 1), args.1);
+    assert_eq!(query_arg::<u8>(&builder, 2), args.2);
+    assert_eq!(query_arg::<u32>(&builder, 3), args.3);
+    assert_eq!(query_arg::<u64>(&builder, 4), args.4);
+    assert_eq!(query_arg::<i32>(&builder, 5), args.5);
+    assert_eq!(query_arg::<PublicKey>(&builder, 6), args.6);
+    assert_eq!(query_arg::<bool>(&builder, 7), args.7);
+}
@@ -0,0 +1,51 @@
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{ApiError, U512};
+
+const CONTRACT_GET_GAS_REMAINING: &str = "get_gas_remaining.wasm";
+const GAS_THRESHOLD: u64 = 1_000_000;
+
+#[repr(u16)]
+enum GetGasRemainingContractError {
+    GasLow = 0,
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_own_code_when_gas_is_low() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_GET_GAS_REMAINING,
+        (U512::from(GAS_THRESHOLD),),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit();
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have a response");
+    let error_message = utils::get_error_message(response);
+
+    assert!(
+        error_message.contains(&format!(
+            "{:?}",
+            ApiError::User(GetGasRemainingContractError::GasLow as u16)
+        )),
+        "expected own revert code, got: {}",
+        error_message
+    );
+    assert!(
+        !error_message.contains("GasLimit"),
+        "expected no out of gas error, got: {}",
+        error_message
+    );
+}
@@ -0,0 +1,58 @@
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::ApiError;
+
+const CONTRACT_GET_KEY_TYPED: &str = "get_key_typed.wasm";
+const COMMAND_UREF: &str = "uref";
+const COMMAND_HASH: &str = "hash";
+const COMMAND_MISMATCH: &str = "mismatch";
+
+/// Calls get_key_typed contract and returns Ok(()) in case no error, or String which is the error
+/// message returned by the engine
+fn call_get_key_typed(command: &str) -> Result<(), String> {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_GET_KEY_TYPED,
+        (String::from(command),),
+    )
+    .build();
+    let result = InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit()
+        .finish();
+
+    if !result.builder().is_error() {
+        return Ok(());
+    }
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response");
+
+    Err(utils::get_error_message(response))
+}
+
+#[ignore]
+#[test]
+fn should_read_uref_via_typed_accessor() {
+    call_get_key_typed(COMMAND_UREF).expect("should read back uref");
+}
+
+#[ignore]
+#[test]
+fn should_read_hash_via_typed_accessor() {
+    call_get_key_typed(COMMAND_HASH).expect("should read back hash");
+}
+
+#[ignore]
+#[test]
+fn should_revert_on_key_variant_mismatch() {
+    let error_message = call_get_key_typed(COMMAND_MISMATCH).expect_err("should fail");
+    assert!(error_message.contains(&format!("{:?}", ApiError::UnexpectedKeyVariant)));
+}
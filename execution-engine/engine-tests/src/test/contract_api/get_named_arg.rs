@@ -0,0 +1,138 @@
+use contract::args_parser::{named_arg, ArgsParser};
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{ApiError, U512};
+
+#[derive(Debug)]
+#[repr(u16)]
+enum GetNamedArgContractError {
+    MissingArgument = 0,
+    InvalidArgument,
+    UnexpectedAmount,
+}
+
+const CONTRACT_GET_NAMED_ARG: &str = "get_named_arg.wasm";
+const AMOUNT_ARG_NAME: &str = "amount";
+const MEMO_ARG_NAME: &str = "memo";
+const AMOUNT: u64 = 42;
+const MEMO: &str = "Hello, world!";
+const POSITIONAL: u8 = 7;
+
+/// Calls get_named_arg contract and returns Ok(()) in case no error, or String which is the
+/// error message returned by the engine
+fn call_get_named_arg(args: impl ArgsParser) -> Result<(), String> {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_GET_NAMED_ARG, args).build();
+    let result = InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit()
+        .finish();
+
+    if !result.builder().is_error() {
+        return Ok(());
+    }
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response");
+
+    Err(utils::get_error_message(response))
+}
+
+#[ignore]
+#[test]
+fn should_mix_named_and_positional_args() {
+    call_get_named_arg((
+        named_arg(AMOUNT_ARG_NAME, U512::from(AMOUNT)),
+        POSITIONAL,
+        named_arg(MEMO_ARG_NAME, String::from(MEMO)),
+    ))
+    .expect("should find named args around a positional one");
+}
+
+#[ignore]
+#[test]
+fn should_find_named_args_regardless_of_position() {
+    call_get_named_arg((
+        named_arg(MEMO_ARG_NAME, String::from(MEMO)),
+        POSITIONAL,
+        named_arg(AMOUNT_ARG_NAME, U512::from(AMOUNT)),
+    ))
+    .expect("should find reordered named args");
+}
+
+#[ignore]
+#[test]
+fn should_not_mistake_positional_string_for_named_arg() {
+    call_get_named_arg((
+        String::from(AMOUNT_ARG_NAME),
+        POSITIONAL,
+        named_arg(MEMO_ARG_NAME, String::from(MEMO)),
+        named_arg(AMOUNT_ARG_NAME, U512::from(AMOUNT)),
+    ))
+    .expect("should skip positional string equal to the name");
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_missing_named_arg_despite_equal_positional_string() {
+    assert!(call_get_named_arg((
+        String::from(AMOUNT_ARG_NAME),
+        POSITIONAL,
+        named_arg(MEMO_ARG_NAME, String::from(MEMO)),
+    ))
+    .expect_err("should fail")
+    .contains(&format!(
+        "{:?}",
+        ApiError::User(GetNamedArgContractError::MissingArgument as u16),
+    )));
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_missing_named_arg() {
+    assert!(
+        call_get_named_arg((named_arg(AMOUNT_ARG_NAME, U512::from(AMOUNT)), POSITIONAL))
+            .expect_err("should fail")
+            .contains(&format!(
+                "{:?}",
+                ApiError::User(GetNamedArgContractError::MissingArgument as u16),
+            ))
+    );
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_invalid_named_arg() {
+    assert!(call_get_named_arg((
+        named_arg(AMOUNT_ARG_NAME, String::from(MEMO)),
+        POSITIONAL,
+        named_arg(MEMO_ARG_NAME, String::from(MEMO)),
+    ))
+    .expect_err("should fail")
+    .contains(&format!(
+        "{:?}",
+        ApiError::User(GetNamedArgContractError::InvalidArgument as u16),
+    )));
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_unexpected_named_arg_value() {
+    assert!(call_get_named_arg((
+        named_arg(AMOUNT_ARG_NAME, U512::from(AMOUNT + 1)),
+        POSITIONAL,
+        named_arg(MEMO_ARG_NAME, String::from(MEMO)),
+    ))
+    .expect_err("should fail")
+    .contains(&format!(
+        "{:?}",
+        ApiError::User(GetNamedArgContractError::UnexpectedAmount as u16),
+    )));
+}
@@ -0,0 +1,50 @@
+use std::convert::TryFrom;
+
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{CLValue, Key};
+
+const CONTRACT_GET_PHASE_SEED: &str = "get_phase_seed.wasm";
+const SEED_NAME: &str = "seed";
+const DEPLOY_HASH_1: [u8; 32] = [1u8; 32];
+const DEPLOY_HASH_2: [u8; 32] = [2u8; 32];
+
+/// Runs get_phase_seed contract with the given deploy hash and returns the seed it stored
+fn get_phase_seed(deploy_hash: [u8; 32]) -> [u8; 32] {
+    let exec_request = ExecuteRequestBuilder::standard_with_deploy_hash(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_GET_PHASE_SEED,
+        (),
+        deploy_hash,
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let stored_value = builder
+        .query(None, Key::Account(DEFAULT_ACCOUNT_ADDR), &[SEED_NAME])
+        .expect("should have seed");
+    CLValue::try_from(stored_value)
+        .expect("should be a CLValue")
+        .into_t()
+        .expect("should be a seed")
+}
+
+#[ignore]
+#[test]
+fn should_derive_same_seed_from_same_deploy_hash() {
+    assert_eq!(get_phase_seed(DEPLOY_HASH_1), get_phase_seed(DEPLOY_HASH_1));
+}
+
+#[ignore]
+#[test]
+fn should_derive_different_seeds_from_different_deploy_hashes() {
+    assert_ne!(get_phase_seed(DEPLOY_HASH_1), get_phase_seed(DEPLOY_HASH_2));
+}
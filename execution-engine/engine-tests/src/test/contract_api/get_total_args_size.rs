@@ -0,0 +1,59 @@
+use std::convert::TryFrom;
+
+use contract::args_parser::ArgsParser;
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{bytesrepr::ToBytes, CLValue, Key, U512};
+
+const CONTRACT_GET_TOTAL_ARGS_SIZE: &str = "get_total_args_size.wasm";
+const TOTAL_ARGS_SIZE_KEY: &str = "total_args_size";
+
+fn get_total_args_size(args: impl ArgsParser) -> u64 {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_GET_TOTAL_ARGS_SIZE, args)
+            .build();
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let stored_value = builder
+        .query(
+            None,
+            Key::Account(DEFAULT_ACCOUNT_ADDR),
+            &[TOTAL_ARGS_SIZE_KEY],
+        )
+        .expect("should have total args size");
+    CLValue::try_from(stored_value)
+        .expect("should be CLValue")
+        .into_t()
+        .expect("should be u64")
+}
+
+fn serialized_args_length(args: impl ArgsParser) -> u64 {
+    args.parse()
+        .expect("should parse args")
+        .into_bytes()
+        .expect("should serialize args")
+        .len() as u64
+}
+
+#[ignore]
+#[test]
+fn should_report_total_args_size() {
+    let args = (String::from("Hello, world!"), U512::from(42), 7u64);
+    assert_eq!(
+        get_total_args_size(args.clone()),
+        serialized_args_length(args)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_report_total_args_size_without_args() {
+    assert_eq!(get_total_args_size(()), serialized_args_length(()));
+}
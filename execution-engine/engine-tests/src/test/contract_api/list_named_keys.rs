@@ -10,6 +10,7 @@ use types::{account::PublicKey, Key};
 const CONTRACT_LIST_NAMED_KEYS: &str = "list_named_keys.wasm";
 const NEW_NAME_ACCOUNT: &str = "Account";
 const NEW_NAME_HASH: &str = "Hash";
+const NEW_NAME_CONTRACT: &str = "Contract";
 
 #[ignore]
 #[test]
@@ -52,3 +53,41 @@ fn should_list_named_keys() {
 
     builder.exec(exec_request).commit().expect_success();
 }
+
+#[ignore]
+#[test]
+fn should_list_three_new_named_keys_in_name_order() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let mint_uref = builder.get_mint_contract_uref().into_read();
+    let pos_uref = builder.get_pos_contract_uref().into_read();
+
+    let mut initial_named_keys = BTreeMap::new();
+    initial_named_keys.insert(MINT_NAME.to_string(), Key::URef(mint_uref));
+    initial_named_keys.insert(POS_NAME.to_string(), Key::URef(pos_uref));
+
+    // Inserted out of name order; the listing must come back sorted regardless.
+    let mut new_named_keys = BTreeMap::new();
+    new_named_keys.insert(NEW_NAME_CONTRACT.to_string(), Key::Hash([3; 32]));
+    new_named_keys.insert(
+        NEW_NAME_ACCOUNT.to_string(),
+        Key::Account(PublicKey::ed25519_from([1; 32])),
+    );
+    new_named_keys.insert(NEW_NAME_HASH.to_string(), Key::Hash([2; 32]));
+
+    let names: Vec<&String> = new_named_keys.keys().collect();
+    assert_eq!(
+        names,
+        vec![NEW_NAME_ACCOUNT, NEW_NAME_CONTRACT, NEW_NAME_HASH]
+    );
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_LIST_NAMED_KEYS,
+        (initial_named_keys, new_named_keys),
+    )
+    .build();
+
+    builder.exec(exec_request).commit().expect_success();
+}
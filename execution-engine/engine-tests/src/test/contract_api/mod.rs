@@ -1,17 +1,41 @@
 mod account;
+mod blake2b;
+mod call_contract_typed;
+mod contract_error;
 mod create_purse;
+mod dictionary;
 mod get_arg;
+mod get_arg_checked;
+mod get_arg_count;
+mod get_arg_gas;
+mod get_arg_validated;
+mod get_arg_versioned;
 mod get_blocktime;
+mod get_call_stack;
 mod get_caller;
+mod get_eight_args;
+mod get_gas_remaining;
+mod get_key_typed;
+mod get_named_arg;
 mod get_phase;
+mod get_phase_seed;
+mod get_total_args_size;
 mod list_named_keys;
 mod local_state;
 mod main_purse;
 mod mint_purse;
+mod print;
+mod query;
+mod read_arg_into;
 mod revert;
+mod revert_with_message;
+mod store_and_call;
 mod subcall;
 mod transfer;
+mod transfer_and_get_balance;
 mod transfer_purse_to_account;
 mod transfer_purse_to_purse;
 mod transfer_stored;
 mod transfer_u512_stored;
+mod uref_read_write;
+mod verify_signature;
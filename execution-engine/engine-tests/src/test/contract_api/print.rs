@@ -0,0 +1,67 @@
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+
+const CONTRACT_PRINT: &str = "print.wasm";
+const FIRST_LINE: &str = "Hello, world!";
+const SECOND_LINE: &str = "Goodbye, world!";
+
+fn lines() -> Vec<String> {
+    vec![String::from(FIRST_LINE), String::from(SECOND_LINE)]
+}
+
+#[ignore]
+#[test]
+fn should_capture_printed_lines() {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_PRINT, (lines(), false))
+            .build();
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(builder.get_logs(0), lines());
+}
+
+#[ignore]
+#[test]
+fn should_capture_lines_printed_before_revert() {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_PRINT, (lines(), true))
+            .build();
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit();
+
+    assert!(builder.is_error());
+    assert_eq!(builder.get_logs(0), lines());
+}
+
+#[ignore]
+#[test]
+fn should_capture_lines_per_exec_call() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_PRINT, (lines(), false))
+            .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_PRINT,
+        (vec![String::from(FIRST_LINE)], false),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    assert_eq!(builder.get_logs(0), lines());
+    assert_eq!(builder.get_logs(1), vec![String::from(FIRST_LINE)]);
+}
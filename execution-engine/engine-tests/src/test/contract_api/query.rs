@@ -0,0 +1,90 @@
+use std::convert::TryFrom;
+
+use assert_matches::assert_matches;
+
+use engine_shared::stored_value::StoredValue;
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{CLValue, Key};
+
+const CONTRACT_QUERY_NAMED_KEYS: &str = "query_named_keys.wasm";
+const VALUE_KEY: &str = "value";
+const CONTRACT_KEY: &str = "contract";
+const MISSING_KEY: &str = "missing";
+const VALUE: &str = "Hello, world!";
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_QUERY_NAMED_KEYS, ())
+            .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+    builder
+}
+
+fn into_string(stored_value: StoredValue) -> String {
+    CLValue::try_from(stored_value)
+        .expect("should be CLValue")
+        .into_t()
+        .expect("should be String")
+}
+
+#[ignore]
+#[test]
+fn should_query_named_key_of_account() {
+    let builder = setup();
+    let stored_value = builder
+        .query(None, Key::Account(DEFAULT_ACCOUNT_ADDR), &[VALUE_KEY])
+        .expect("should have value");
+    assert_eq!(into_string(stored_value), VALUE);
+}
+
+#[ignore]
+#[test]
+fn should_query_named_key_of_contract() {
+    let builder = setup();
+
+    // Walk from the account through the contract's named keys.
+    let stored_value = builder
+        .query(
+            None,
+            Key::Account(DEFAULT_ACCOUNT_ADDR),
+            &[CONTRACT_KEY, VALUE_KEY],
+        )
+        .expect("should have value");
+    assert_eq!(into_string(stored_value), VALUE);
+
+    // Start directly from the contract's hash.
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    let contract_key = *account
+        .named_keys()
+        .get(CONTRACT_KEY)
+        .expect("should have contract key");
+    assert_matches!(contract_key, Key::Hash(_));
+    let stored_value = builder
+        .query(None, contract_key, &[VALUE_KEY])
+        .expect("should have value");
+    assert_eq!(into_string(stored_value), VALUE);
+}
+
+#[ignore]
+#[test]
+fn should_fail_to_query_missing_named_key() {
+    let builder = setup();
+    let error = builder
+        .query(None, Key::Account(DEFAULT_ACCOUNT_ADDR), &[MISSING_KEY])
+        .expect_err("should fail");
+    assert!(
+        error.contains(&format!("Name {} not found in Account", MISSING_KEY)),
+        error
+    );
+}
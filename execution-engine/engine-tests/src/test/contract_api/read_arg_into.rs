@@ -0,0 +1,74 @@
+use contract::args_parser::ArgsParser;
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, ApiError};
+
+#[derive(Debug)]
+#[repr(u16)]
+enum ReadArgIntoContractError {
+    UnexpectedPublicKey = 1,
+}
+
+const CONTRACT_READ_ARG_INTO: &str = "read_arg_into.wasm";
+const OTHER_PUBLIC_KEY: PublicKey = PublicKey::ed25519_from([42; 32]);
+
+/// Calls read_arg_into contract and returns Ok(()) in case no error, or String which is the
+/// error message returned by the engine
+fn call_read_arg_into(args: impl ArgsParser) -> Result<(), String> {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_READ_ARG_INTO, args).build();
+    let result = InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit()
+        .finish();
+
+    if !result.builder().is_error() {
+        return Ok(());
+    }
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response");
+
+    Err(utils::get_error_message(response))
+}
+
+#[ignore]
+#[test]
+fn should_read_public_key_into_buffer() {
+    call_read_arg_into((DEFAULT_ACCOUNT_ADDR,)).expect("should read caller's public key");
+}
+
+#[ignore]
+#[test]
+fn should_read_unexpected_public_key_into_buffer() {
+    assert!(call_read_arg_into((OTHER_PUBLIC_KEY,))
+        .expect_err("should fail")
+        .contains(&format!(
+            "{:?}",
+            ApiError::User(ReadArgIntoContractError::UnexpectedPublicKey as u16),
+        )));
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_buffer_too_small() {
+    let oversized_arg = String::from("longer than thirty two bytes in total");
+    assert!(call_read_arg_into((oversized_arg,))
+        .expect_err("should fail")
+        .contains(&format!("{:?}", ApiError::BufferTooSmall)));
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_missing_arg() {
+    assert!(call_read_arg_into(())
+        .expect_err("should fail")
+        .contains(&format!("{:?}", ApiError::MissingArgument)));
+}
@@ -0,0 +1,70 @@
+use engine_core::{
+    engine_state,
+    execution::{self, RevertMessage},
+};
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{ApiError, REVERT_MESSAGE_MAX_LENGTH};
+
+const CONTRACT_REVERT_WITH_MESSAGE: &str = "revert_with_message.wasm";
+const REVERT_CODE: u16 = 7;
+const MESSAGE: &str = "amount must be nonzero";
+
+/// Calls revert_with_message contract and returns the execution error returned by the engine
+fn call_revert_with_message(message: String) -> execution::Error {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_REVERT_WITH_MESSAGE,
+        (message,),
+    )
+    .build();
+    let result = InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit()
+        .finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response");
+
+    match response[0].as_error() {
+        Some(engine_state::Error::Exec(error)) => error.clone(),
+        other => panic!("should fail with an execution error, but got {:?}", other),
+    }
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_message() {
+    let expected_error = execution::Error::RevertWithMessage {
+        error: ApiError::User(REVERT_CODE),
+        message: RevertMessage {
+            text: String::from(MESSAGE),
+            truncated: false,
+        },
+    };
+    let error = call_revert_with_message(String::from(MESSAGE));
+    assert_eq!(format!("{:?}", error), format!("{:?}", expected_error));
+    assert!(!error.to_string().contains("(truncated)"), "{}", error);
+}
+
+#[ignore]
+#[test]
+fn should_truncate_long_revert_message() {
+    let long_message = "a".repeat(REVERT_MESSAGE_MAX_LENGTH + 1);
+    let expected_error = execution::Error::RevertWithMessage {
+        error: ApiError::User(REVERT_CODE),
+        message: RevertMessage {
+            text: "a".repeat(REVERT_MESSAGE_MAX_LENGTH),
+            truncated: true,
+        },
+    };
+    let error = call_revert_with_message(long_message);
+    assert_eq!(format!("{:?}", error), format!("{:?}", expected_error));
+    // Clients receive the displayed error in the deploy result, so it must carry the marker.
+    assert!(error.to_string().ends_with(" (truncated)"), "{}", error);
+}
@@ -0,0 +1,33 @@
+use std::convert::TryFrom;
+
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{CLValue, Key};
+
+const CONTRACT_STORE_AND_CALL: &str = "store_and_call.wasm";
+const COUNTER_KEY: &str = "counter";
+
+#[ignore]
+#[test]
+fn should_store_and_call_contract_in_same_deploy() {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_STORE_AND_CALL, ()).build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let stored_value = builder
+        .query(None, Key::Account(DEFAULT_ACCOUNT_ADDR), &[COUNTER_KEY])
+        .expect("should have counter");
+    let counter: i32 = CLValue::try_from(stored_value)
+        .expect("should be CLValue")
+        .into_t()
+        .expect("should be i32");
+    assert_eq!(counter, 1, "inner call should have incremented the counter");
+}
@@ -350,3 +350,55 @@ fn should_transfer_total_amount() {
         .expect_success()
         .finish();
 }
+
+#[ignore]
+#[test]
+fn should_transfer_between_funded_accounts() {
+    let initial_balance = U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE);
+    let transfer_amount = *TRANSFER_2_AMOUNT;
+    let account_0 = PublicKey::ed25519_from(utils::account_addr(0));
+    let account_1 = PublicKey::ed25519_from(utils::account_addr(1));
+    let account_2 = PublicKey::ed25519_from(utils::account_addr(2));
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis_with_funded_accounts(3, initial_balance);
+
+    let exec_request_1 = ExecuteRequestBuilder::standard(
+        account_0,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        (account_1, transfer_amount),
+    )
+    .build();
+    let exec_request_2 = ExecuteRequestBuilder::standard(
+        account_1,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        (account_2, transfer_amount),
+    )
+    .build();
+    builder
+        .exec(exec_request_1)
+        .expect_success()
+        .commit()
+        .exec(exec_request_2)
+        .expect_success()
+        .commit();
+
+    let get_balance = |public_key| {
+        let account = builder
+            .get_account(public_key)
+            .expect("should have funded account");
+        builder.get_purse_balance(account.main_purse())
+    };
+    let gas_cost = |index| {
+        Motes::from_gas(builder.exec_costs(index)[0], CONV_RATE)
+            .expect("should convert gas to motes")
+            .value()
+    };
+
+    assert_eq!(
+        get_balance(account_0),
+        initial_balance - gas_cost(0) - transfer_amount
+    );
+    assert_eq!(get_balance(account_1), initial_balance - gas_cost(1));
+    assert_eq!(get_balance(account_2), initial_balance + transfer_amount);
+}
@@ -0,0 +1,85 @@
+use std::convert::TryFrom;
+
+use engine_test_support::{
+    internal::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
+        DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{
+    account::PublicKey, bytesrepr::FromBytes, ApiError, CLTyped, CLValue, Key, TransferResult,
+    TransferredTo, U512,
+};
+
+const CONTRACT_TRANSFER_AND_GET_BALANCE: &str = "transfer_and_get_balance.wasm";
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
+
+fn get_named_value<T: CLTyped + FromBytes>(builder: &InMemoryWasmTestBuilder, name: &str) -> T {
+    let stored_value = builder
+        .query(None, Key::Account(DEFAULT_ACCOUNT_ADDR), &[name])
+        .expect("should have named value");
+    CLValue::try_from(stored_value)
+        .expect("should be a CLValue")
+        .into_t()
+        .expect("should have expected type")
+}
+
+#[ignore]
+#[test]
+fn should_transfer_and_update_both_balances() {
+    let amount = *DEFAULT_PAYMENT;
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_AND_GET_BALANCE,
+        (ACCOUNT_1_ADDR, amount),
+    )
+    .build();
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let expected_result: TransferResult = Ok(TransferredTo::NewAccount);
+    let transfer_result: String = get_named_value(&builder, "transfer_result");
+    assert_eq!(transfer_result, format!("{:?}", expected_result));
+
+    let balance_before: U512 = get_named_value(&builder, "balance_before");
+    let balance_after: U512 = get_named_value(&builder, "balance_after");
+    assert_eq!(balance_before - balance_after, amount);
+
+    let account_1 = builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should have account 1");
+    assert_eq!(builder.get_purse_balance(account_1.main_purse()), amount);
+}
+
+#[ignore]
+#[test]
+fn should_return_error_on_insufficient_funds() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_AND_GET_BALANCE,
+        (ACCOUNT_1_ADDR, U512::from(u64::max_value())),
+    )
+    .build();
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let expected_result: TransferResult = Err(ApiError::Transfer);
+    let transfer_result: String = get_named_value(&builder, "transfer_result");
+    assert_eq!(transfer_result, format!("{:?}", expected_result));
+
+    let balance_before: U512 = get_named_value(&builder, "balance_before");
+    let balance_after: U512 = get_named_value(&builder, "balance_after");
+    assert_eq!(balance_before, balance_after);
+    assert!(builder
+        .query(None, Key::Account(ACCOUNT_1_ADDR), &[])
+        .is_err());
+}
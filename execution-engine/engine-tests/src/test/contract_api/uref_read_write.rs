@@ -0,0 +1,50 @@
+use assert_matches::assert_matches;
+
+use engine_core::{engine_state::Error, execution};
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::AccessRights;
+
+const CONTRACT_UREF_READ_WRITE: &str = "uref_read_write.wasm";
+const COMMAND_CYCLE: &str = "cycle";
+const COMMAND_WRITE_READ_ONLY: &str = "write-read-only";
+
+fn run_uref_read_write(command: &str) -> InMemoryWasmTestBuilder {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_UREF_READ_WRITE,
+        (String::from(command),),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit();
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_create_write_and_read_uref() {
+    run_uref_read_write(COMMAND_CYCLE).expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_not_write_through_read_only_uref() {
+    let builder = run_uref_read_write(COMMAND_WRITE_READ_ONLY);
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("there should be a response");
+    let error = response[0].as_error().expect("should have an error");
+    let write = AccessRights::WRITE;
+    assert_matches!(
+        error,
+        Error::Exec(execution::Error::InvalidAccess { required }) if *required == write
+    );
+}
@@ -0,0 +1,177 @@
+use contract::args_parser::ArgsParser;
+use ed25519_dalek::{ExpandedSecretKey, PublicKey as Ed25519PublicKey, SecretKey};
+
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{
+    account::{PublicKey, Signature},
+    ApiError,
+};
+
+const CONTRACT_VERIFY_SIGNATURE: &str = "verify_signature.wasm";
+const CONTRACT_VERIFY_SIGNATURE_RAW: &str = "verify_signature_raw.wasm";
+const SECRET_KEY_BYTES: [u8; 32] = [7u8; 32];
+const OTHER_SECRET_KEY_BYTES: [u8; 32] = [8u8; 32];
+const MESSAGE: &[u8] = b"Hello, world!";
+const TAMPERED_MESSAGE: &[u8] = b"Hello, world?";
+
+#[repr(u16)]
+enum VerifySignatureContractError {
+    InvalidSignature = 0,
+}
+
+fn sign(message: &[u8]) -> (PublicKey, Signature) {
+    sign_with(&SECRET_KEY_BYTES, message)
+}
+
+fn sign_with(secret_key_bytes: &[u8; 32], message: &[u8]) -> (PublicKey, Signature) {
+    let secret_key = SecretKey::from_bytes(secret_key_bytes).expect("should create secret key");
+    let public_key = Ed25519PublicKey::from(&secret_key);
+    let signature = ExpandedSecretKey::from(&secret_key).sign(message, &public_key);
+    (
+        PublicKey::ed25519_from(public_key.to_bytes()),
+        Signature::ed25519_from(signature.to_bytes()),
+    )
+}
+
+fn call_verify_signature(
+    public_key: PublicKey,
+    message: &[u8],
+    signature: Signature,
+) -> Result<(), String> {
+    run(
+        CONTRACT_VERIFY_SIGNATURE,
+        (public_key, message.to_vec(), signature),
+    )
+}
+
+/// Calls verify_signature_raw contract, which hands the given bytes to the host without checking
+/// that they form a well-sized public key and signature
+fn call_verify_signature_raw(
+    public_key_bytes: &[u8],
+    message: &[u8],
+    signature_bytes: &[u8],
+) -> Result<(), String> {
+    run(
+        CONTRACT_VERIFY_SIGNATURE_RAW,
+        (
+            public_key_bytes.to_vec(),
+            message.to_vec(),
+            signature_bytes.to_vec(),
+        ),
+    )
+}
+
+fn run(contract: &str, args: impl ArgsParser) -> Result<(), String> {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, contract, args).build();
+    let result = InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit()
+        .finish();
+
+    if !result.builder().is_error() {
+        return Ok(());
+    }
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response");
+
+    Err(utils::get_error_message(response))
+}
+
+#[ignore]
+#[test]
+fn should_verify_valid_signature() {
+    let (public_key, signature) = sign(MESSAGE);
+    call_verify_signature(public_key, MESSAGE, signature).expect("should verify signature");
+}
+
+#[ignore]
+#[test]
+fn should_reject_tampered_message() {
+    let (public_key, signature) = sign(MESSAGE);
+    let error_message = call_verify_signature(public_key, TAMPERED_MESSAGE, signature)
+        .expect_err("should reject signature");
+    assert!(error_message.contains(&format!(
+        "{:?}",
+        ApiError::User(VerifySignatureContractError::InvalidSignature as u16)
+    )));
+}
+
+fn invalid_signature_message() -> String {
+    format!(
+        "{:?}",
+        ApiError::User(VerifySignatureContractError::InvalidSignature as u16)
+    )
+}
+
+#[ignore]
+#[test]
+fn should_reject_wrong_public_key() {
+    let (_, signature) = sign(MESSAGE);
+    let (other_public_key, _) = sign_with(&OTHER_SECRET_KEY_BYTES, MESSAGE);
+    let error_message = call_verify_signature(other_public_key, MESSAGE, signature)
+        .expect_err("should reject signature");
+    assert!(error_message.contains(&invalid_signature_message()));
+}
+
+#[ignore]
+#[test]
+fn should_reject_malformed_public_key() {
+    let (_, signature) = sign(MESSAGE);
+    // The y-coordinate 2 doesn't correspond to any point on the curve, so these bytes don't
+    // decompress to a public key.
+    let mut public_key_bytes = [0u8; 32];
+    public_key_bytes[0] = 2;
+    let error_message = call_verify_signature(
+        PublicKey::ed25519_from(public_key_bytes),
+        MESSAGE,
+        signature,
+    )
+    .expect_err("should reject signature");
+    assert!(error_message.contains(&invalid_signature_message()));
+}
+
+#[ignore]
+#[test]
+fn should_reject_malformed_signature() {
+    let (public_key, _) = sign(MESSAGE);
+    let error_message =
+        call_verify_signature(public_key, MESSAGE, Signature::ed25519_from([0xffu8; 64]))
+            .expect_err("should reject signature");
+    assert!(error_message.contains(&invalid_signature_message()));
+}
+
+#[ignore]
+#[test]
+fn should_fail_with_signature_of_wrong_length() {
+    let (public_key, signature) = sign(MESSAGE);
+    let signature_bytes = signature.as_bytes();
+
+    let too_short = &signature_bytes[..signature_bytes.len() - 1];
+    let error_message = call_verify_signature_raw(public_key.as_bytes(), MESSAGE, too_short)
+        .expect_err("should fail with short signature");
+    assert!(error_message.contains("BytesRepr"), "{}", error_message);
+
+    let mut too_long = signature_bytes.to_vec();
+    too_long.push(0);
+    let error_message = call_verify_signature_raw(public_key.as_bytes(), MESSAGE, &too_long)
+        .expect_err("should fail with long signature");
+    assert!(error_message.contains("BytesRepr"), "{}", error_message);
+}
+
+#[ignore]
+#[test]
+fn should_verify_valid_signature_passed_as_raw_bytes() {
+    let (public_key, signature) = sign(MESSAGE);
+    call_verify_signature_raw(public_key.as_bytes(), MESSAGE, signature.as_bytes())
+        .expect("should verify signature");
+}
@@ -0,0 +1,72 @@
+use engine_shared::stored_value::StoredValue;
+use engine_test_support::{
+    internal::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
+        DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, Key, U512};
+
+const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
+const ACCOUNT_2_ADDR: PublicKey = PublicKey::ed25519_from([2u8; 32]);
+const ACCOUNT_3_ADDR: PublicKey = PublicKey::ed25519_from([3u8; 32]);
+
+fn transfer(builder: &mut InMemoryWasmTestBuilder, target: PublicKey, amount: U512) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        (target, amount),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+}
+
+fn get_balance(builder: &InMemoryWasmTestBuilder, public_key: PublicKey) -> Option<U512> {
+    match builder.query(None, Key::Account(public_key), &[]) {
+        Ok(StoredValue::Account(account)) => Some(builder.get_purse_balance(account.main_purse())),
+        _ => None,
+    }
+}
+
+#[ignore]
+#[test]
+fn should_run_divergent_deploys_from_snapshot() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+    transfer(&mut builder, ACCOUNT_1_ADDR, *DEFAULT_PAYMENT);
+
+    let snapshot = builder.get_post_state_hash();
+
+    let mut branch_a = builder.from_state_hash(snapshot.clone());
+    transfer(&mut branch_a, ACCOUNT_2_ADDR, U512::from(1));
+
+    let mut branch_b = builder.from_state_hash(snapshot.clone());
+    transfer(&mut branch_b, ACCOUNT_3_ADDR, U512::from(2));
+
+    // Both branches see the transfer committed before the snapshot, but not each other's.
+    assert_eq!(
+        get_balance(&branch_a, ACCOUNT_1_ADDR),
+        Some(*DEFAULT_PAYMENT)
+    );
+    assert_eq!(get_balance(&branch_a, ACCOUNT_2_ADDR), Some(U512::from(1)));
+    assert_eq!(get_balance(&branch_a, ACCOUNT_3_ADDR), None);
+
+    assert_eq!(
+        get_balance(&branch_b, ACCOUNT_1_ADDR),
+        Some(*DEFAULT_PAYMENT)
+    );
+    assert_eq!(get_balance(&branch_b, ACCOUNT_2_ADDR), None);
+    assert_eq!(get_balance(&branch_b, ACCOUNT_3_ADDR), Some(U512::from(2)));
+
+    assert_ne!(
+        branch_a.get_post_state_hash(),
+        branch_b.get_post_state_hash()
+    );
+
+    // The original builder is still at the snapshot.
+    assert_eq!(builder.get_post_state_hash(), snapshot);
+    assert_eq!(get_balance(&builder, ACCOUNT_2_ADDR), None);
+    assert_eq!(get_balance(&builder, ACCOUNT_3_ADDR), None);
+}
@@ -0,0 +1,58 @@
+use assert_matches::assert_matches;
+
+use engine_shared::transform::Transform;
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::Key;
+
+const CONTRACT_GET_PHASE_SEED: &str = "get_phase_seed.wasm";
+const SEED_NAME: &str = "seed";
+
+#[ignore]
+#[test]
+fn should_expose_write_and_add_keys_transforms_of_deploy() {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_GET_PHASE_SEED, ()).build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let seed_key = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(SEED_NAME)
+        .cloned()
+        .expect("should have seed key");
+
+    let transforms = builder
+        .get_deploy_transforms(0)
+        .expect("should have transforms of first deploy");
+    assert!(builder.get_deploy_transforms(1).is_none());
+
+    let seed_transform = transforms
+        .iter()
+        .find(|(key, _)| *key == seed_key.normalize())
+        .map(|(_, transform)| transform)
+        .expect("should have transform at seed key");
+    let seed_value = builder
+        .query(None, Key::Account(DEFAULT_ACCOUNT_ADDR), &[SEED_NAME])
+        .expect("should have seed value");
+    assert_eq!(*seed_transform, Transform::Write(seed_value));
+
+    let account_transform = transforms
+        .iter()
+        .find(|(key, _)| *key == Key::Account(DEFAULT_ACCOUNT_ADDR))
+        .map(|(_, transform)| transform)
+        .expect("should have transform at account key");
+    assert_matches!(
+        account_transform,
+        Transform::AddKeys(named_keys) if named_keys.get(SEED_NAME) == Some(&seed_key)
+    );
+}
@@ -0,0 +1,54 @@
+use contract::args_parser::ArgsParser;
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, ApiError};
+
+const CONTRACT_REVERT: &str = "revert.wasm";
+const CONTRACT_GET_CALLER: &str = "get_caller.wasm";
+const CONTRACT_ENDLESS_LOOP: &str = "endless_loop.wasm";
+const UNKNOWN_PUBLIC_KEY: PublicKey = PublicKey::ed25519_from([1u8; 32]);
+
+/// Runs `contract` as session code of the default account and asserts it failed cleanly
+fn run_and_assert_clean_failure(contract: &str, args: impl ArgsParser) -> ApiError {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, contract, args).build();
+    let result = InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit()
+        .finish();
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response");
+
+    utils::should_fail_cleanly(&response[0])
+}
+
+#[ignore]
+#[test]
+fn should_fail_cleanly_on_revert() {
+    assert_eq!(
+        run_and_assert_clean_failure(CONTRACT_REVERT, ()),
+        ApiError::User(100)
+    );
+}
+
+#[ignore]
+#[test]
+#[should_panic(expected = "should fail cleanly")]
+fn should_not_fail_cleanly_on_failed_assertion() {
+    run_and_assert_clean_failure(CONTRACT_GET_CALLER, (UNKNOWN_PUBLIC_KEY,));
+}
+
+#[ignore]
+#[test]
+#[should_panic(expected = "should fail cleanly")]
+fn should_not_fail_cleanly_on_out_of_gas() {
+    run_and_assert_clean_failure(CONTRACT_ENDLESS_LOOP, ());
+}
@@ -0,0 +1,79 @@
+use assert_matches::assert_matches;
+
+use contract::args_parser::ArgsParser;
+use engine_core::engine_state::{EngineConfig, Error};
+use engine_shared::gas::Gas;
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::bytesrepr::ToBytes;
+
+const CONTRACT_DO_NOTHING: &str = "do_nothing.wasm";
+const PAYLOAD_LENGTH: usize = 1024;
+
+fn payload_args(length: usize) -> (Vec<u8>,) {
+    (vec![7u8; length],)
+}
+
+fn serialized_length(args: impl ArgsParser) -> usize {
+    args.parse()
+        .expect("should convert to `Vec<CLValue>`")
+        .into_bytes()
+        .expect("should serialize args")
+        .len()
+}
+
+fn builder_with_max_args_bytes(max_args_bytes: usize) -> InMemoryWasmTestBuilder {
+    let engine_config = EngineConfig::new().with_max_args_bytes(Some(max_args_bytes));
+    let mut builder = InMemoryWasmTestBuilder::with_engine_config(engine_config);
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_run_deploy_with_args_at_limit() {
+    let max_args_bytes = serialized_length(payload_args(PAYLOAD_LENGTH));
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_DO_NOTHING,
+        payload_args(PAYLOAD_LENGTH),
+    )
+    .build();
+
+    builder_with_max_args_bytes(max_args_bytes)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+}
+
+#[ignore]
+#[test]
+fn should_reject_deploy_with_args_over_limit_before_execution() {
+    let max_args_bytes = serialized_length(payload_args(PAYLOAD_LENGTH));
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_DO_NOTHING,
+        payload_args(PAYLOAD_LENGTH + 1),
+    )
+    .build();
+
+    let mut builder = builder_with_max_args_bytes(max_args_bytes);
+    builder.exec(exec_request);
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("there should be a response");
+
+    let precondition_failure = utils::get_precondition_failure(response);
+    let expected_actual = max_args_bytes + 1;
+    assert_matches!(
+        precondition_failure,
+        Error::ArgsTooLarge { max, actual } if *max == max_args_bytes && *actual == expected_actual
+    );
+    // The wasm never ran, so no gas was charged.
+    assert_eq!(response[0].cost(), Gas::default());
+}
@@ -1,3 +1,9 @@
+mod branching;
+mod deploy_transforms;
+mod fail_cleanly;
+mod max_args_bytes;
 mod non_standard_payment;
+mod out_of_gas;
 mod preconditions;
+mod sequential_deploys;
 mod stored_contracts;
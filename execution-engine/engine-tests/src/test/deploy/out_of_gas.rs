@@ -0,0 +1,58 @@
+use engine_shared::transform::Transform;
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::Key;
+
+const CONTRACT_PUT_KEY_AND_LOOP: &str = "put_key_and_loop.wasm";
+const CONTRACT_REVERT: &str = "revert.wasm";
+const MARKER_KEY_NAME: &str = "marker";
+
+#[ignore]
+#[test]
+fn should_report_out_of_gas_without_committing_session_effects() {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_PUT_KEY_AND_LOOP, ())
+            .build();
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit();
+
+    assert!(builder.is_error());
+    assert!(builder.is_out_of_gas(0));
+
+    let transforms = builder
+        .get_deploy_transforms(0)
+        .expect("should have transforms");
+    let adds_named_key = transforms.iter().any(|(key, transform)| {
+        *key == Key::Account(DEFAULT_ACCOUNT_ADDR)
+            && match transform {
+                Transform::AddKeys(_) => true,
+                _ => false,
+            }
+    });
+    assert!(!adds_named_key, "session effects should not be committed");
+
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    assert!(!account.named_keys().contains_key(MARKER_KEY_NAME));
+}
+
+#[ignore]
+#[test]
+fn should_not_report_revert_as_out_of_gas() {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_REVERT, ()).build();
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit();
+
+    assert!(builder.is_error());
+    assert!(!builder.is_out_of_gas(0));
+}
@@ -0,0 +1,46 @@
+use engine_test_support::{
+    internal::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
+        DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::account::PublicKey;
+
+const CONTRACT_GET_CALLER: &str = "get_caller.wasm";
+const CONTRACT_TRANSFER_PURSE_TO_ACCOUNT: &str = "transfer_purse_to_account.wasm";
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
+
+#[ignore]
+#[test]
+fn should_observe_writes_of_previous_deploy() {
+    // The second deploy can only run if the account created by the first one exists.
+    let exec_request_1 = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_PURSE_TO_ACCOUNT,
+        (ACCOUNT_1_ADDR, *DEFAULT_PAYMENT),
+    )
+    .build();
+    let exec_request_2 =
+        ExecuteRequestBuilder::standard(ACCOUNT_1_ADDR, CONTRACT_GET_CALLER, (ACCOUNT_1_ADDR,))
+            .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec_commit_sequentially(vec![exec_request_1, exec_request_2]);
+
+    assert_eq!(builder.get_exec_responses_count(), 2);
+    for index in 0..2 {
+        let response = builder
+            .get_exec_response(index)
+            .expect("should have a response");
+        assert!(
+            response.iter().all(|result| !result.is_failure()),
+            "deploy {} should succeed: {:?}",
+            index,
+            response
+        );
+    }
+    assert!(builder.get_account(ACCOUNT_1_ADDR).is_some());
+}
@@ -4,6 +4,7 @@ use alloc::{boxed::Box, vec::Vec};
 use core::{
     convert::TryFrom,
     fmt::{Debug, Display, Formatter},
+    str::FromStr,
 };
 
 use failure::Fail;
@@ -23,6 +24,28 @@ pub struct TryFromIntError(());
 #[derive(Debug)]
 pub struct TryFromSliceForPublicKeyError(());
 
+/// Associated error type of `FromStr` and `TryFrom<&str>` for [`PublicKey`].
+#[derive(Fail, Debug, PartialEq, Eq, Copy, Clone)]
+pub enum FromStrForPublicKeyError {
+    /// The input was not exactly twice [`ED25519_LENGTH`] hex characters long.
+    #[fail(
+        display = "Invalid public key length: expected {} hex characters, got {}",
+        expected, actual
+    )]
+    InvalidLength {
+        /// The required number of hex characters.
+        expected: usize,
+        /// The number of characters in the input.
+        actual: usize,
+    },
+    /// The input contained a character which is not a hex digit.
+    #[fail(display = "Invalid hex character at index {}", index)]
+    InvalidHex {
+        /// The index of the offending character in the input.
+        index: usize,
+    },
+}
+
 /// The various types of action which can be performed in the context of a given account.
 #[repr(u32)]
 pub enum ActionType {
@@ -207,10 +230,46 @@ pub enum PublicKey {
     Ed25519(Ed25519),
 }
 
+/// Formats the public key as lowercase hex, the form accepted by its `FromStr` implementation.
 impl Display for PublicKey {
     fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
-        let PublicKey::Ed25519(ed25519) = self;
-        write!(f, "PublicKey({})", ed25519)
+        write!(f, "{}", base16::encode_lower(self.as_bytes()))
+    }
+}
+
+/// Parses an Ed25519 public key from hex, accepting both lowercase and uppercase digits.
+impl FromStr for PublicKey {
+    type Err = FromStrForPublicKeyError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let expected = 2 * ED25519_LENGTH;
+        if input.len() != expected {
+            return Err(FromStrForPublicKeyError::InvalidLength {
+                expected,
+                actual: input.len(),
+            });
+        }
+        let mut bytes = [0u8; ED25519_LENGTH];
+        base16::decode_slice(input, &mut bytes).map_err(|error| match error {
+            base16::DecodeError::InvalidByte { index, .. } => {
+                FromStrForPublicKeyError::InvalidHex { index }
+            }
+            base16::DecodeError::InvalidLength { length } => {
+                FromStrForPublicKeyError::InvalidLength {
+                    expected,
+                    actual: length,
+                }
+            }
+        })?;
+        Ok(PublicKey::ed25519_from(bytes))
+    }
+}
+
+impl TryFrom<&str> for PublicKey {
+    type Error = FromStrForPublicKeyError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        input.parse()
     }
 }
 
@@ -244,7 +303,8 @@ impl PublicKey {
 
 impl Debug for PublicKey {
     fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
-        write!(f, "{}", self)
+        let PublicKey::Ed25519(ed25519) = self;
+        write!(f, "PublicKey({})", ed25519)
     }
 }
 
@@ -280,6 +340,68 @@ impl FromBytes for PublicKey {
     }
 }
 
+/// The length in bytes of a [`Signature`].
+pub const ED25519_SIGNATURE_LENGTH: usize = 64;
+
+/// The number of bytes in a serialized [`Signature`].
+pub const SIGNATURE_SERIALIZED_LENGTH: usize = ED25519_SIGNATURE_LENGTH;
+
+/// A type alias for the raw bytes of an Ed25519 signature.
+pub type Ed25519SignatureBytes = [u8; ED25519_SIGNATURE_LENGTH];
+
+/// An Ed25519 signature of a message, made with the secret key of a [`PublicKey`].
+#[derive(Clone, Copy)]
+pub struct Signature(Ed25519SignatureBytes);
+
+impl Signature {
+    /// Constructs a new `Signature` using Ed25519 signature bytes.
+    pub const fn ed25519_from(bytes: Ed25519SignatureBytes) -> Signature {
+        Signature(bytes)
+    }
+
+    /// Returns the raw bytes of the signature as a `slice`.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl PartialEq for Signature {
+    fn eq(&self, other: &Signature) -> bool {
+        self.0[..] == other.0[..]
+    }
+}
+
+impl Eq for Signature {}
+
+impl Debug for Signature {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        write!(f, "Signature({})", HexFmt(&self.0[..]))
+    }
+}
+
+impl CLTyped for Signature {
+    fn cl_type() -> CLType {
+        CLType::FixedList(Box::new(CLType::U8), ED25519_SIGNATURE_LENGTH as u32)
+    }
+}
+
+impl ToBytes for Signature {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        self.0.to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        SIGNATURE_SERIALIZED_LENGTH
+    }
+}
+
+impl FromBytes for Signature {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (bytes, rem) = Ed25519SignatureBytes::from_bytes(bytes)?;
+        Ok((Signature::ed25519_from(bytes), rem))
+    }
+}
+
 /// Errors that can occur while adding a new [`PublicKey`] to an account's associated keys map.
 #[derive(PartialEq, Eq, Fail, Debug, Copy, Clone)]
 #[repr(i32)]
@@ -386,7 +508,7 @@ impl TryFrom<i32> for UpdateKeyFailure {
 
 #[cfg(test)]
 mod tests {
-    use std::{convert::TryFrom, vec::Vec};
+    use std::{convert::TryFrom, string::ToString, vec::Vec};
 
     use super::*;
 
@@ -396,6 +518,62 @@ mod tests {
         let public_key = PublicKey::ed25519_try_from(&bytes[..]).expect("should create public key");
         assert_eq!(&bytes, &public_key.as_bytes());
     }
+
+    #[test]
+    fn public_key_hex_roundtrip() {
+        let bytes: Vec<u8> = (0..32).collect();
+        let public_key = PublicKey::ed25519_try_from(&bytes[..]).expect("should create public key");
+        let hex = public_key.to_string();
+        assert_eq!(
+            hex,
+            "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"
+        );
+        assert_eq!(hex.parse::<PublicKey>(), Ok(public_key));
+        assert_eq!(PublicKey::try_from(hex.as_str()), Ok(public_key));
+    }
+
+    #[test]
+    fn public_key_display_should_be_lowercase_hex() {
+        let public_key = PublicKey::ed25519_from([0xab; 32]);
+        assert_eq!(public_key.to_string(), "ab".repeat(32));
+        assert!(format!("{:?}", public_key).starts_with("PublicKey("));
+    }
+
+    #[test]
+    fn public_key_from_uppercase_hex() {
+        let public_key = PublicKey::ed25519_from([0xab; 32]);
+        let uppercase = public_key.to_string().to_uppercase();
+        assert_eq!(uppercase.parse::<PublicKey>(), Ok(public_key));
+    }
+
+    #[test]
+    fn public_key_from_odd_length_hex_should_fail() {
+        let hex = "0".repeat(63);
+        assert_eq!(
+            hex.parse::<PublicKey>(),
+            Err(FromStrForPublicKeyError::InvalidLength {
+                expected: 64,
+                actual: 63
+            })
+        );
+    }
+
+    #[test]
+    fn public_key_from_non_hex_should_fail() {
+        let mut hex = "0".repeat(64);
+        hex.replace_range(10..11, "g");
+        assert_eq!(
+            hex.parse::<PublicKey>(),
+            Err(FromStrForPublicKeyError::InvalidHex { index: 10 })
+        );
+    }
+
+    #[test]
+    fn signature_serialization_roundtrip() {
+        let signature = Signature::ed25519_from([7u8; ED25519_SIGNATURE_LENGTH]);
+        crate::bytesrepr::test_serialization_roundtrip(&signature);
+    }
+
     #[test]
     fn ed25519_public_key_from_slice_too_small() {
         let _public_key =
@@ -45,6 +45,10 @@ const POS_ERROR_MIN: u32 = POS_ERROR_OFFSET;
 /// Maximum value of Proof of Stake error's inclusive range.
 const POS_ERROR_MAX: u32 = RESERVED_ERROR_MAX;
 
+/// The maximum number of bytes of a revert message which the host will read.  Longer messages are
+/// truncated.
+pub const REVERT_MESSAGE_MAX_LENGTH: usize = 256;
+
 /// Errors which can be encountered while running a smart contract.
 ///
 /// An `ApiError` can be converted to a `u32` in order to be passed via the execution engine's
@@ -416,6 +420,8 @@ impl From<bytesrepr::Error> for ApiError {
             bytesrepr::Error::Formatting => ApiError::Formatting,
             bytesrepr::Error::LeftOverBytes => ApiError::LeftOverBytes,
             bytesrepr::Error::OutOfMemory => ApiError::OutOfMemory,
+            bytesrepr::Error::InvalidChar => ApiError::Formatting,
+            bytesrepr::Error::DuplicateSetElement => ApiError::Formatting,
         }
     }
 }
@@ -5,7 +5,10 @@ use crate::bytesrepr::{Error, FromBytes, ToBytes, U64_SERIALIZED_LENGTH};
 /// The number of bytes in a serialized [`BlockTime`].
 pub const BLOCKTIME_SERIALIZED_LENGTH: usize = U64_SERIALIZED_LENGTH;
 
-/// A newtype wrapping a [`u64`] which represents the block time.
+/// A newtype wrapping a [`u64`] which represents the block time, in milliseconds since the Unix
+/// epoch.
+///
+/// This is the timestamp of the block containing the deploy being executed.
 #[derive(Clone, Copy, Default, Debug, PartialEq, Eq, PartialOrd)]
 pub struct BlockTime(u64);
 
@@ -1,4 +1,10 @@
 //! Contains serialization and deserialization code for types used throughout the system.
+//!
+//! All integer types are serialized in little-endian byte order.  Fixed-width integers occupy
+//! exactly their size in bytes, while [`U128`](crate::U128), [`U256`](crate::U256) and
+//! [`U512`](crate::U512) are serialized as a single byte holding the number of significant bytes,
+//! followed by those bytes in little-endian order.  Where big-endian encoding is required, e.g. for
+//! interoperability with other chains, the value can be wrapped in a [`BigEndian`].
 
 // Can be removed once https://github.com/rust-lang/rustfmt/issues/3362 is resolved.
 #[rustfmt::skip]
@@ -7,10 +13,17 @@ use alloc::vec;
 use alloc::alloc::{alloc, Layout};
 #[cfg(not(feature = "no-unstable-features"))]
 use alloc::collections::TryReserveError;
-use alloc::{collections::BTreeMap, string::String, vec::Vec};
-use core::mem::{self, MaybeUninit};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    vec::Vec,
+};
 #[cfg(feature = "no-unstable-features")]
 use core::ptr::NonNull;
+use core::{
+    cmp,
+    mem::{self, MaybeUninit},
+};
 
 use failure::Fail;
 
@@ -30,6 +43,8 @@ pub const U16_SERIALIZED_LENGTH: usize = mem::size_of::<u16>();
 pub const U32_SERIALIZED_LENGTH: usize = mem::size_of::<u32>();
 /// The number of bytes in a serialized `u64`.
 pub const U64_SERIALIZED_LENGTH: usize = mem::size_of::<u64>();
+/// The number of bytes in a serialized `char`.
+pub const CHAR_SERIALIZED_LENGTH: usize = U32_SERIALIZED_LENGTH;
 /// The number of bytes in a serialized [`U128`](crate::U128).
 pub const U128_SERIALIZED_LENGTH: usize = mem::size_of::<u128>();
 /// The number of bytes in a serialized [`U256`](crate::U256).
@@ -97,6 +112,13 @@ pub enum Error {
     /// Out of memory error.
     #[fail(display = "Serialization error: out of memory")]
     OutOfMemory,
+    /// A `char` was serialized as a `u32` which isn't a valid Unicode scalar value.
+    #[fail(display = "Deserialization error: invalid char")]
+    InvalidChar,
+    /// A set was serialized with an element which isn't greater than its predecessor, i.e. with a
+    /// duplicate or out-of-order element.
+    #[fail(display = "Deserialization error: duplicate or unordered set element")]
+    DuplicateSetElement,
 }
 
 #[cfg(not(feature = "no-unstable-features"))]
@@ -285,6 +307,66 @@ impl FromBytes for u64 {
     }
 }
 
+impl ToBytes for char {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        (*self as u32).to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        CHAR_SERIALIZED_LENGTH
+    }
+}
+
+impl FromBytes for char {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (code_point, remainder) = u32::from_bytes(bytes)?;
+        // Surrogates and values above `char::MAX` are not valid Unicode scalar values.
+        let result = core::char::from_u32(code_point).ok_or(Error::InvalidChar)?;
+        Ok((result, remainder))
+    }
+}
+
+/// A wrapper around an integer which is serialized and deserialized in big-endian byte order.
+///
+/// The layout otherwise matches that of the wrapped type, i.e. fixed-width integers occupy exactly
+/// their size in bytes, and [`U128`](crate::U128), [`U256`](crate::U256) and [`U512`](crate::U512)
+/// are prefixed with a single byte holding the number of significant bytes.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BigEndian<T>(pub T);
+
+macro_rules! impl_to_from_bytes_for_big_endian {
+    ($($type:ty => $length:expr),+) => {
+        $(
+            impl ToBytes for BigEndian<$type> {
+                fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+                    Ok(self.0.to_be_bytes().to_vec())
+                }
+
+                fn serialized_length(&self) -> usize {
+                    $length
+                }
+            }
+
+            impl FromBytes for BigEndian<$type> {
+                fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+                    let mut result = [0u8; $length];
+                    let (bytes, remainder) = safe_split_at(bytes, $length)?;
+                    result.copy_from_slice(bytes);
+                    Ok((BigEndian(<$type>::from_be_bytes(result)), remainder))
+                }
+            }
+        )+
+    }
+}
+
+impl_to_from_bytes_for_big_endian! {
+    i32 => I32_SERIALIZED_LENGTH,
+    i64 => I64_SERIALIZED_LENGTH,
+    u16 => U16_SERIALIZED_LENGTH,
+    u32 => U32_SERIALIZED_LENGTH,
+    u64 => U64_SERIALIZED_LENGTH
+}
+
 impl ToBytes for String {
     fn to_bytes(&self) -> Result<Vec<u8>, Error> {
         self.as_str().to_bytes()
@@ -390,7 +472,9 @@ fn try_vec_with_capacity<T>(capacity: usize) -> Result<Vec<T>, Error> {
 fn vec_from_bytes<T: FromBytes>(bytes: &[u8]) -> Result<(Vec<T>, &[u8]), Error> {
     let (count, mut stream) = u32::from_bytes(bytes)?;
 
-    let mut result = try_vec_with_capacity(count as usize)?;
+    // Every element other than a zero-sized one consumes at least one byte, so a declared count
+    // larger than the remaining input can't be valid and mustn't drive the allocation.
+    let mut result = try_vec_with_capacity(cmp::min(count as usize, stream.len()))?;
     for _ in 0..count {
         let (value, remainder) = T::from_bytes(stream)?;
         result.push(value);
@@ -638,6 +722,45 @@ where
     }
 }
 
+impl<T: ToBytes> ToBytes for BTreeSet<T> {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut result = allocate_buffer(self)?;
+
+        let num_elements = self.len() as u32;
+        result.append(&mut num_elements.to_bytes()?);
+
+        for element in self.iter() {
+            result.append(&mut element.to_bytes()?);
+        }
+
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        U32_SERIALIZED_LENGTH + self.iter().map(ToBytes::serialized_length).sum::<usize>()
+    }
+}
+
+impl<T: FromBytes + Ord> FromBytes for BTreeSet<T> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (num_elements, mut stream) = u32::from_bytes(bytes)?;
+        let mut result = BTreeSet::new();
+        for _ in 0..num_elements {
+            let (element, rem) = T::from_bytes(stream)?;
+            // A well-formed set is serialized in strictly ascending order, which also rules out
+            // the same element appearing twice.
+            if let Some(last) = result.iter().next_back() {
+                if element <= *last {
+                    return Err(Error::DuplicateSetElement);
+                }
+            }
+            result.insert(element);
+            stream = rem;
+        }
+        Ok((result, stream))
+    }
+}
+
 impl<T: ToBytes> ToBytes for Option<T> {
     fn to_bytes(&self) -> Result<Vec<u8>, Error> {
         match self {
@@ -874,13 +997,18 @@ mod tests {
 
 #[cfg(test)]
 mod proptests {
-    use std::vec::Vec;
+    use std::{collections::BTreeSet, vec::Vec};
 
-    use proptest::{collection::vec, prelude::*};
+    use proptest::{
+        collection::{btree_set, vec},
+        prelude::*,
+    };
 
     use crate::{
-        bytesrepr::{self, FromBytes, ToBytes, U32_SERIALIZED_LENGTH},
+        account::PublicKey,
+        bytesrepr::{self, BigEndian, FromBytes, ToBytes, U32_SERIALIZED_LENGTH},
         gens::*,
+        U512,
     };
 
     proptest! {
@@ -904,6 +1032,11 @@ mod proptests {
             bytesrepr::test_serialization_roundtrip(&u);
         }
 
+        #[test]
+        fn test_char(c in any::<char>()) {
+            bytesrepr::test_serialization_roundtrip(&c);
+        }
+
         #[test]
         fn test_i32(u in any::<i32>()) {
             bytesrepr::test_serialization_roundtrip(&u);
@@ -919,6 +1052,46 @@ mod proptests {
             bytesrepr::test_serialization_roundtrip(&u);
         }
 
+        #[test]
+        fn test_big_endian_i32(u in any::<i32>()) {
+            bytesrepr::test_serialization_roundtrip(&BigEndian(u));
+        }
+
+        #[test]
+        fn test_big_endian_i64(u in any::<i64>()) {
+            bytesrepr::test_serialization_roundtrip(&BigEndian(u));
+        }
+
+        #[test]
+        fn test_big_endian_u16(u in any::<u16>()) {
+            bytesrepr::test_serialization_roundtrip(&BigEndian(u));
+        }
+
+        #[test]
+        fn test_big_endian_u32(u in any::<u32>()) {
+            bytesrepr::test_serialization_roundtrip(&BigEndian(u));
+        }
+
+        #[test]
+        fn test_big_endian_u64(u in any::<u64>()) {
+            bytesrepr::test_serialization_roundtrip(&BigEndian(u));
+        }
+
+        #[test]
+        fn test_big_endian_u128(u in u128_arb()) {
+            bytesrepr::test_serialization_roundtrip(&BigEndian(u));
+        }
+
+        #[test]
+        fn test_big_endian_u256(u in u256_arb()) {
+            bytesrepr::test_serialization_roundtrip(&BigEndian(u));
+        }
+
+        #[test]
+        fn test_big_endian_u512(u in u512_arb()) {
+            bytesrepr::test_serialization_roundtrip(&BigEndian(u));
+        }
+
         #[test]
         fn test_u8_slice_32(s in u8_slice_32()) {
             bytesrepr::test_serialization_roundtrip(&s);
@@ -939,6 +1112,11 @@ mod proptests {
             bytesrepr::test_serialization_roundtrip(&u);
         }
 
+        #[test]
+        fn test_btree_set(s in btree_set(public_key_arb(), 0..20)) {
+            bytesrepr::test_serialization_roundtrip(&s);
+        }
+
         #[test]
         fn test_uref_map(m in named_keys_arb(20)) {
             bytesrepr::test_serialization_roundtrip(&m);
@@ -1040,6 +1218,109 @@ mod proptests {
         }
     }
 
+    #[test]
+    fn should_serialize_set_of_public_keys() {
+        let set: BTreeSet<PublicKey> = (0..3u8).map(|i| PublicKey::ed25519_from([i; 32])).collect();
+        bytesrepr::test_serialization_roundtrip(&set);
+    }
+
+    #[test]
+    fn should_fail_to_deserialize_set_with_duplicates() {
+        let public_key = PublicKey::ed25519_from([1; 32]);
+        let duplicates = vec![public_key, public_key].to_bytes().unwrap();
+        assert_eq!(
+            bytesrepr::deserialize::<BTreeSet<PublicKey>>(duplicates),
+            Err(bytesrepr::Error::DuplicateSetElement)
+        );
+    }
+
+    #[test]
+    fn should_fail_to_deserialize_set_out_of_order() {
+        let descending: Vec<PublicKey> = (0..3u8)
+            .rev()
+            .map(|i| PublicKey::ed25519_from([i; 32]))
+            .collect();
+        assert_eq!(
+            bytesrepr::deserialize::<BTreeSet<PublicKey>>(descending.to_bytes().unwrap()),
+            Err(bytesrepr::Error::DuplicateSetElement)
+        );
+
+        let unsorted = vec![3u8, 1, 2].to_bytes().unwrap();
+        assert_eq!(
+            bytesrepr::deserialize::<BTreeSet<u8>>(unsorted),
+            Err(bytesrepr::Error::DuplicateSetElement)
+        );
+    }
+
+    #[test]
+    fn should_serialize_multi_byte_char() {
+        let crab = '\u{1F980}';
+        assert_eq!(crab.to_bytes().unwrap(), vec![0x80, 0xF9, 0x01, 0x00]);
+        bytesrepr::test_serialization_roundtrip(&crab);
+    }
+
+    #[test]
+    fn should_fail_to_deserialize_invalid_char() {
+        let surrogate = 0xD800u32.to_bytes().unwrap();
+        assert_eq!(
+            bytesrepr::deserialize::<char>(surrogate),
+            Err(bytesrepr::Error::InvalidChar)
+        );
+
+        let out_of_range = (u32::from(core::char::MAX) + 1).to_bytes().unwrap();
+        assert_eq!(
+            bytesrepr::deserialize::<char>(out_of_range),
+            Err(bytesrepr::Error::InvalidChar)
+        );
+    }
+
+    #[test]
+    fn big_endian_should_differ_from_little_endian() {
+        let value: u32 = 0x0102_0304;
+        let le_bytes = value.to_bytes().unwrap();
+        let be_bytes = BigEndian(value).to_bytes().unwrap();
+        assert_eq!(le_bytes, vec![4, 3, 2, 1]);
+        assert_eq!(be_bytes, vec![1, 2, 3, 4]);
+        assert_eq!(bytesrepr::deserialize::<u32>(le_bytes).unwrap(), value);
+        assert_eq!(
+            bytesrepr::deserialize::<BigEndian<u32>>(be_bytes).unwrap(),
+            BigEndian(value)
+        );
+
+        let value = U512::from(0x0102_0304u64);
+        let le_bytes = value.to_bytes().unwrap();
+        let be_bytes = BigEndian(value).to_bytes().unwrap();
+        assert_eq!(le_bytes, vec![4, 4, 3, 2, 1]);
+        assert_eq!(be_bytes, vec![4, 1, 2, 3, 4]);
+        assert_eq!(bytesrepr::deserialize::<U512>(le_bytes).unwrap(), value);
+        assert_eq!(
+            bytesrepr::deserialize::<BigEndian<U512>>(be_bytes).unwrap(),
+            BigEndian(value)
+        );
+    }
+
+    #[test]
+    fn should_serialize_jagged_nested_vec() {
+        let matrix: Vec<Vec<U512>> = vec![
+            vec![],
+            vec![U512::from(1)],
+            vec![U512::from(2), U512::max_value(), U512::zero()],
+        ];
+        bytesrepr::test_serialization_roundtrip(&matrix);
+    }
+
+    #[test]
+    fn should_fail_to_deserialize_nested_vec_with_oversized_inner_length() {
+        let mut bytes = 2u32.to_bytes().unwrap();
+        bytes.append(&mut vec![U512::from(1)].to_bytes().unwrap());
+        // The second inner vec claims `u32::MAX` elements but provides none.
+        bytes.append(&mut u32::max_value().to_bytes().unwrap());
+        assert_eq!(
+            bytesrepr::deserialize::<Vec<Vec<U512>>>(bytes),
+            Err(bytesrepr::Error::EarlyEndOfStream)
+        );
+    }
+
     #[test]
     fn vec_u8_from_bytes() {
         let data: Vec<u8> = vec![1, 2, 3, 4, 5];
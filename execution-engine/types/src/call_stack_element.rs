@@ -0,0 +1,113 @@
+use alloc::vec::Vec;
+
+use crate::{
+    account::PublicKey,
+    bytesrepr::{self, Error, FromBytes, ToBytes, U8_SERIALIZED_LENGTH},
+    CLType, CLTyped, Key,
+};
+
+const SESSION_TAG: u8 = 0;
+const STORED_CONTRACT_TAG: u8 = 1;
+
+/// An entry in the call stack of the currently executing deploy.
+///
+/// The first entry is always the session or payment code of the deploy.  Each call to a stored
+/// contract pushes a further entry, which is popped again when the called contract returns.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum CallStackElement {
+    /// Session or payment code, executing in the context of the given account.
+    Session(PublicKey),
+    /// A stored contract, called via the given key.
+    StoredContract(Key),
+}
+
+impl ToBytes for CallStackElement {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        match self {
+            CallStackElement::Session(public_key) => {
+                result.push(SESSION_TAG);
+                result.append(&mut public_key.to_bytes()?);
+            }
+            CallStackElement::StoredContract(key) => {
+                result.push(STORED_CONTRACT_TAG);
+                result.append(&mut key.to_bytes()?);
+            }
+        }
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        U8_SERIALIZED_LENGTH
+            + match self {
+                CallStackElement::Session(public_key) => public_key.serialized_length(),
+                CallStackElement::StoredContract(key) => key.serialized_length(),
+            }
+    }
+}
+
+impl FromBytes for CallStackElement {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        match tag {
+            SESSION_TAG => {
+                let (public_key, remainder) = PublicKey::from_bytes(remainder)?;
+                Ok((CallStackElement::Session(public_key), remainder))
+            }
+            STORED_CONTRACT_TAG => {
+                let (key, remainder) = Key::from_bytes(remainder)?;
+                Ok((CallStackElement::StoredContract(key), remainder))
+            }
+            _ => Err(Error::Formatting),
+        }
+    }
+}
+
+/// A `CallStackElement` is laid out exactly like a `Result<Key, PublicKey>`, with
+/// [`CallStackElement::Session`] in place of `Err` and [`CallStackElement::StoredContract`] in
+/// place of `Ok`, so it has the matching `CLType`.
+impl CLTyped for CallStackElement {
+    fn cl_type() -> CLType {
+        Result::<Key, PublicKey>::cl_type()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_serialize_call_stack_elements() {
+        let session = CallStackElement::Session(PublicKey::ed25519_from([1; 32]));
+        bytesrepr::test_serialization_roundtrip(&session);
+
+        let stored_contract = CallStackElement::StoredContract(Key::Hash([2; 32]));
+        bytesrepr::test_serialization_roundtrip(&stored_contract);
+
+        bytesrepr::test_serialization_roundtrip(&vec![session, stored_contract]);
+    }
+
+    #[test]
+    fn should_serialize_like_result_of_key_or_public_key() {
+        let public_key = PublicKey::ed25519_from([1; 32]);
+        let key = Key::Hash([2; 32]);
+        assert_eq!(
+            CallStackElement::Session(public_key).to_bytes(),
+            Err::<Key, PublicKey>(public_key).to_bytes()
+        );
+        assert_eq!(
+            CallStackElement::StoredContract(key).to_bytes(),
+            Ok::<Key, PublicKey>(key).to_bytes()
+        );
+    }
+
+    #[test]
+    fn should_fail_to_deserialize_unknown_tag() {
+        let mut bytes = vec![STORED_CONTRACT_TAG + 1];
+        bytes.append(&mut Key::Hash([2; 32]).to_bytes().unwrap());
+        assert_eq!(
+            bytesrepr::deserialize::<CallStackElement>(bytes),
+            Err(Error::Formatting)
+        );
+    }
+}
@@ -1,13 +1,13 @@
 use alloc::{
     boxed::Box,
-    collections::{BTreeMap, VecDeque},
+    collections::{BTreeMap, BTreeSet, VecDeque},
     string::String,
     vec::Vec,
 };
 use core::mem;
 
 use crate::{
-    bytesrepr::{self, FromBytes, ToBytes},
+    bytesrepr::{self, BigEndian, FromBytes, ToBytes},
     Key, URef, U128, U256, U512,
 };
 
@@ -330,6 +330,13 @@ impl CLTyped for u32 {
     }
 }
 
+/// A `char` is represented by its Unicode scalar value.
+impl CLTyped for char {
+    fn cl_type() -> CLType {
+        CLType::U32
+    }
+}
+
 impl CLTyped for u64 {
     fn cl_type() -> CLType {
         CLType::U64
@@ -354,6 +361,13 @@ impl CLTyped for U512 {
     }
 }
 
+/// A [`BigEndian`] value has the `CLType` of the wrapped integer; only its byte layout differs.
+impl<T: CLTyped> CLTyped for BigEndian<T> {
+    fn cl_type() -> CLType {
+        T::cl_type()
+    }
+}
+
 impl CLTyped for () {
     fn cl_type() -> CLType {
         CLType::Unit
@@ -396,6 +410,12 @@ impl<T: CLTyped> CLTyped for Vec<T> {
     }
 }
 
+impl<T: CLTyped> CLTyped for BTreeSet<T> {
+    fn cl_type() -> CLType {
+        CLType::List(Box::new(T::cl_type()))
+    }
+}
+
 macro_rules! impl_cl_typed_for_array {
     ($($N:literal)+) => {
         $(
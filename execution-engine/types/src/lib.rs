@@ -28,12 +28,14 @@ pub mod account;
 pub mod api_error;
 mod block_time;
 pub mod bytesrepr;
+mod call_stack_element;
 mod cl_type;
 mod cl_value;
 mod contract_ref;
 #[cfg(any(feature = "gens", test))]
 pub mod gens;
 mod key;
+pub mod named_arg;
 mod phase;
 mod protocol_version;
 mod semver;
@@ -46,8 +48,9 @@ mod uref;
 pub use crate::uint::{UIntParseError, U128, U256, U512};
 pub use access_rights::{AccessRights, ACCESS_RIGHTS_SERIALIZED_LENGTH};
 #[doc(inline)]
-pub use api_error::ApiError;
+pub use api_error::{ApiError, REVERT_MESSAGE_MAX_LENGTH};
 pub use block_time::{BlockTime, BLOCKTIME_SERIALIZED_LENGTH};
+pub use call_stack_element::CallStackElement;
 pub use cl_type::{named_key_type, CLType, CLTyped};
 pub use cl_value::{CLTypeMismatch, CLValue, CLValueError};
 pub use contract_ref::ContractRef;
@@ -0,0 +1,124 @@
+//! Contains [`NamedArg`], a wrapper allowing an argument to be looked up by name rather than by
+//! its position.
+
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+use crate::{
+    bytesrepr::{self, Error, FromBytes, ToBytes, U32_SERIALIZED_LENGTH},
+    CLType, CLTyped,
+};
+
+/// The tag with which every serialized [`NamedArg`] starts.
+///
+/// Read as a `u32`, this is the length prefix of a `String` or list argument, but no such argument
+/// can be `u32::MAX` elements long, so a positional argument can't be mistaken for a named one.
+const NAMED_ARG_TAG: u32 = u32::MAX;
+
+/// An argument paired with a name.
+///
+/// It is serialized as a `u32` tag of `u32::MAX`, followed by the name as a `String` and then the
+/// value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NamedArg<T> {
+    name: String,
+    value: T,
+}
+
+impl<T> NamedArg<T> {
+    /// Constructs a new `NamedArg`.
+    pub fn new(name: String, value: T) -> Self {
+        NamedArg { name, value }
+    }
+
+    /// Returns the name of the argument.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns a reference to the value of the argument.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Consumes `self`, returning the value of the argument.
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+/// Splits the serialized form of a [`NamedArg`] into its name and the still-serialized value.
+///
+/// Returns `None` if `bytes` doesn't start with the tag of a named argument followed by a valid
+/// name.
+pub fn split_name(bytes: &[u8]) -> Option<(String, &[u8])> {
+    let (tag, remainder) = u32::from_bytes(bytes).ok()?;
+    if tag != NAMED_ARG_TAG {
+        return None;
+    }
+    String::from_bytes(remainder).ok()
+}
+
+/// The `CLType` of a [`NamedArg`] reflects its layout, i.e. a tuple of the tag, name and value.
+impl<T: CLTyped> CLTyped for NamedArg<T> {
+    fn cl_type() -> CLType {
+        CLType::Tuple3([
+            Box::new(CLType::U32),
+            Box::new(CLType::String),
+            Box::new(T::cl_type()),
+        ])
+    }
+}
+
+impl<T: ToBytes> ToBytes for NamedArg<T> {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut NAMED_ARG_TAG.to_bytes()?);
+        result.append(&mut self.name.to_bytes()?);
+        result.append(&mut self.value.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        U32_SERIALIZED_LENGTH + self.name.serialized_length() + self.value.serialized_length()
+    }
+}
+
+impl<T: FromBytes> FromBytes for NamedArg<T> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (tag, remainder) = u32::from_bytes(bytes)?;
+        if tag != NAMED_ARG_TAG {
+            return Err(Error::Formatting);
+        }
+        let (name, remainder) = String::from_bytes(remainder)?;
+        let (value, remainder) = T::from_bytes(remainder)?;
+        Ok((NamedArg { name, value }, remainder))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization_roundtrip() {
+        let named_arg = NamedArg::new(String::from("amount"), 42u64);
+        bytesrepr::test_serialization_roundtrip(&named_arg);
+    }
+
+    #[test]
+    fn should_split_name_from_value() {
+        let bytes = NamedArg::new(String::from("amount"), 42u64)
+            .to_bytes()
+            .unwrap();
+        let (name, value_bytes) = split_name(&bytes).expect("should split");
+        assert_eq!(name, "amount");
+        assert_eq!(bytesrepr::deserialize::<u64>(value_bytes.to_vec()), Ok(42));
+    }
+
+    #[test]
+    fn should_not_split_positional_string() {
+        let bytes = String::from("amount").to_bytes().unwrap();
+        assert!(split_name(&bytes).is_none());
+        assert!(bytesrepr::deserialize::<NamedArg<()>>(bytes).is_err());
+    }
+}
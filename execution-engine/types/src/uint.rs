@@ -1,9 +1,12 @@
 use alloc::vec::Vec;
 
 use num_integer::Integer;
-use num_traits::{AsPrimitive, Bounded, Num, One, Unsigned, WrappingAdd, WrappingSub, Zero};
+use num_traits::{
+    AsPrimitive, Bounded, CheckedAdd, CheckedMul, CheckedSub, Num, One, Unsigned, WrappingAdd,
+    WrappingSub, Zero,
+};
 
-use crate::bytesrepr::{self, Error, FromBytes, ToBytes, U8_SERIALIZED_LENGTH};
+use crate::bytesrepr::{self, BigEndian, Error, FromBytes, ToBytes, U8_SERIALIZED_LENGTH};
 
 #[allow(
     clippy::assign_op_pattern,
@@ -74,6 +77,36 @@ macro_rules! impl_traits_for_uint {
             }
         }
 
+        impl ToBytes for BigEndian<$type> {
+            fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+                let mut buf = [0u8; $total_bytes];
+                self.0.to_big_endian(&mut buf);
+                let mut result: Vec<u8> = Vec::with_capacity(self.serialized_length());
+                let non_zero_bytes = buf.iter().skip_while(|b| **b == 0);
+                result.push(non_zero_bytes.clone().count() as u8);
+                result.extend(non_zero_bytes);
+                Ok(result)
+            }
+
+            fn serialized_length(&self) -> usize {
+                self.0.serialized_length()
+            }
+        }
+
+        impl FromBytes for BigEndian<$type> {
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+                let (num_bytes, rem): (u8, &[u8]) = FromBytes::from_bytes(bytes)?;
+
+                if num_bytes > $total_bytes {
+                    Err(Error::Formatting)
+                } else {
+                    let (value, rem) = bytesrepr::safe_split_at(rem, num_bytes as usize)?;
+                    let result = $type::from_big_endian(value);
+                    Ok((BigEndian(result), rem))
+                }
+            }
+        }
+
         // Trait implementations for unifying U* as numeric types
         impl Zero for $type {
             fn zero() -> Self {
@@ -132,6 +165,26 @@ macro_rules! impl_traits_for_uint {
             }
         }
 
+        // The inherent `checked_*` and `saturating_*` methods generated by `construct_uint!` are
+        // the primary API; these allow the same operations via generic num_traits bounds.
+        impl CheckedAdd for $type {
+            fn checked_add(&self, other: &$type) -> Option<$type> {
+                $type::checked_add(*self, *other)
+            }
+        }
+
+        impl CheckedSub for $type {
+            fn checked_sub(&self, other: &$type) -> Option<$type> {
+                $type::checked_sub(*self, *other)
+            }
+        }
+
+        impl CheckedMul for $type {
+            fn checked_mul(&self, other: &$type) -> Option<$type> {
+                $type::checked_mul(*self, *other)
+            }
+        }
+
         impl Integer for $type {
             /// Unsigned integer division. Returns the same result as `div` (`/`).
             #[inline]
@@ -388,6 +441,53 @@ macro_rules! impl_traits_for_uint {
             fn underflow_sub_test() {
                 let _ = $type::zero() - $type::from(1);
             }
+
+            #[test]
+            fn checked_add_test() {
+                assert_eq!($type::MAX.checked_add($type::from(1)), None);
+                assert_eq!($type::MAX.checked_add($type::zero()), Some($type::MAX));
+                assert_eq!(
+                    ($type::MAX - $type::from(1)).checked_add($type::from(1)),
+                    Some($type::MAX)
+                );
+                assert_eq!(CheckedAdd::checked_add(&$type::MAX, &$type::from(1)), None);
+            }
+
+            #[test]
+            fn checked_sub_test() {
+                assert_eq!($type::zero().checked_sub($type::from(1)), None);
+                assert_eq!(
+                    $type::zero().checked_sub($type::zero()),
+                    Some($type::zero())
+                );
+                assert_eq!(
+                    $type::from(1).checked_sub($type::from(1)),
+                    Some($type::zero())
+                );
+                assert_eq!(
+                    CheckedSub::checked_sub(&$type::zero(), &$type::from(1)),
+                    None
+                );
+            }
+
+            #[test]
+            fn checked_mul_test() {
+                assert_eq!($type::MAX.checked_mul($type::from(2)), None);
+                assert_eq!($type::MAX.checked_mul($type::from(1)), Some($type::MAX));
+                assert_eq!($type::MAX.checked_mul($type::zero()), Some($type::zero()));
+                assert_eq!(CheckedMul::checked_mul(&$type::MAX, &$type::from(2)), None);
+            }
+
+            #[test]
+            fn saturating_sub_test() {
+                assert_eq!($type::zero().saturating_sub($type::from(1)), $type::zero());
+                assert_eq!($type::from(5).saturating_sub($type::from(7)), $type::zero());
+                assert_eq!(
+                    $type::from(7).saturating_sub($type::from(5)),
+                    $type::from(2)
+                );
+                assert_eq!($type::MAX.saturating_sub($type::MAX), $type::zero());
+            }
         }
     };
 }